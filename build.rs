@@ -0,0 +1,70 @@
+//! Generates a test for every example fixture and part, so that adding an
+//! example under `examples/<year>-<day>/` is enough to have it checked. See
+//! `src/examples.rs` for the fixture format.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=examples");
+
+    let mut examples = Vec::new();
+    for dir in fs::read_dir("examples").into_iter().flatten() {
+        let dir = dir.unwrap().path();
+        let Some((year, day)) = dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split_once('-'))
+            .and_then(|(year, day)| Some((year.parse::<u16>().ok()?, day.parse::<u8>().ok()?)))
+        else {
+            continue;
+        };
+        for file in fs::read_dir(&dir).unwrap() {
+            let path = file.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "toml") {
+                let name = path.file_stem().unwrap().to_str().unwrap().to_string();
+                let expected: toml::Table = fs::read_to_string(&path)
+                    .unwrap()
+                    .parse()
+                    .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+                for part in ["part_one", "part_two"] {
+                    if expected.contains_key(part) {
+                        examples.push((year, day, name.clone(), part));
+                    }
+                }
+            }
+        }
+    }
+    examples.sort();
+
+    // Group the tests into `year<year>::day<day>` modules, so the usual
+    // `cargo test year2023::day05` filter picks them up too.
+    let mut code = String::new();
+    let mut module = None;
+    for (year, day, name, part) in examples {
+        if module != Some((year, day)) {
+            if module.is_some() {
+                code.push_str("}\n");
+            }
+            if module.map(|(y, _)| y) != Some(year) {
+                if module.is_some() {
+                    code.push_str("}\n");
+                }
+                writeln!(code, "mod year{year} {{").unwrap();
+            }
+            writeln!(code, "mod day{day:02} {{").unwrap();
+            module = Some((year, day));
+        }
+        let variant = if part == "part_one" { "One" } else { "Two" };
+        writeln!(
+            code,
+            "#[test] fn example_{name}_{part}() {{ super::super::check({year}, {day}, {name:?}, crate::Part::{variant}); }}"
+        )
+        .unwrap();
+    }
+    if module.is_some() {
+        code.push_str("}\n}\n");
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    fs::write(out, code).unwrap();
+}
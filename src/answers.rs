@@ -0,0 +1,145 @@
+//! Confirmed answers to the real puzzle inputs, recorded per year in
+//! `answers/<year>.toml` so that `aoc verify` can catch regressions:
+//!
+//! ```toml
+//! [05]
+//! part_one = 650599855
+//! part_two = 1240035
+//! ```
+//!
+//! Answers are integers or strings. Either part may be left out until it has
+//! been confirmed, and a year without a file simply has no answers yet.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{Answer, Part};
+
+/// The recorded answers for one year, by day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<u8, [Option<Answer>; 2]>);
+
+impl Answers {
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.0.get(&day)?[part as usize].as_ref()
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: toml::Table = s
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_string())?;
+        let mut answers = BTreeMap::new();
+        for (key, value) in table {
+            let day = key.parse().map_err(|_| format!("[{key}] is not a day"))?;
+            let toml::Value::Table(parts) = value else {
+                return Err(format!("{key} should be a table"));
+            };
+            let entry = parts_from_table(parts).map_err(|e| format!("[{key}] {e}"))?;
+            answers.insert(day, entry);
+        }
+        Ok(Answers(answers))
+    }
+}
+
+/// Reads `part_one` and `part_two` out of a table, rejecting any other keys.
+pub(crate) fn parts_from_table(table: toml::Table) -> Result<[Option<Answer>; 2], String> {
+    let mut parts = [None, None];
+    for (name, value) in table {
+        let part = match name.as_str() {
+            "part_one" => Part::One,
+            "part_two" => Part::Two,
+            _ => return Err(format!("has unknown key {name:?}")),
+        };
+        parts[part as usize] = Some(match value {
+            toml::Value::Integer(n) => Answer::from(n),
+            toml::Value::String(s) => Answer::from(s),
+            _ => return Err(format!("{name} should be an integer or string")),
+        });
+    }
+    Ok(parts)
+}
+
+#[derive(Debug)]
+pub struct Error {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Where the answers for a year are recorded, independent of the working
+/// directory.
+pub fn path(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("answers")
+        .join(format!("{year}.toml"))
+}
+
+/// Loads the answers recorded for `year`, if there are any.
+pub fn load(year: u16) -> Result<Answers, Error> {
+    let path = path(year);
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+        Err(err) => {
+            return Err(Error {
+                path,
+                message: err.to_string(),
+            })
+        }
+    };
+    text.parse().map_err(|message| Error { path, message })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_parse() {
+        let answers: Answers = indoc! {r#"
+            [01]
+            part_one = 24000
+            part_two = "CMZ"
+
+            [5]
+            part_one = 35
+        "#}
+        .parse()
+        .unwrap();
+        assert_eq!(answers.get(1, Part::One), Some(&Answer::from(24000)));
+        assert_eq!(answers.get(1, Part::Two), Some(&Answer::from("CMZ")));
+        assert_eq!(answers.get(5, Part::One), Some(&Answer::from(35)));
+        assert_eq!(answers.get(5, Part::Two), None);
+        assert_eq!(answers.get(6, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!("[first]\npart_one = 1".parse::<Answers>().is_err());
+        assert!("[01]\npart_three = 1".parse::<Answers>().is_err());
+        assert!("[01]\npart_one = 1.5".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_checked_in_answers_parse() {
+        for year in [2022, 2023] {
+            load(year).unwrap();
+        }
+    }
+}
@@ -0,0 +1,270 @@
+//! Timing each phase of a solution separately: parsing, part one and part two.
+
+use std::{
+    fmt,
+    hint::black_box,
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{parse::ParseError, Solution};
+
+/// How many times to run each phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Untimed runs before sampling starts, to warm up caches and the allocator.
+    pub warmup: usize,
+    pub samples: usize,
+    /// Stop warming up or sampling a phase once it has taken this long, so
+    /// that slow days still finish. At least one sample is always taken.
+    pub time_limit: Duration,
+    /// Give up on a phase altogether once it has run this long, for the
+    /// solutions whose single sample never finishes.
+    pub timeout: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            warmup: 1,
+            samples: 10,
+            time_limit: Duration::from_secs(5),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Summary statistics over the samples of one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub samples: usize,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort();
+        Stats {
+            median: samples[samples.len() / 2],
+            min: samples[0],
+            max: samples[samples.len() - 1],
+            samples: samples.len(),
+        }
+    }
+}
+
+/// Formats as `median (min–max)`, honouring width and alignment.
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&format!(
+            "{} ({}–{})",
+            human(self.median),
+            human(self.min),
+            human(self.max)
+        ))
+    }
+}
+
+/// A duration rounded to a readable unit, e.g. `12.3ms`.
+pub fn human(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..=999 => format!("{nanos}ns"),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", duration.as_secs_f64()),
+    }
+}
+
+/// Runs `f` repeatedly as `options` describe and times each sample.
+pub fn measure<T>(options: &Options, mut f: impl FnMut() -> T) -> Stats {
+    let start = Instant::now();
+    for _ in 0..options.warmup {
+        if start.elapsed() >= options.time_limit {
+            break;
+        }
+        black_box(f());
+    }
+
+    let start = Instant::now();
+    let mut samples = Vec::with_capacity(options.samples);
+    while samples.is_empty()
+        || (samples.len() < options.samples && start.elapsed() < options.time_limit)
+    {
+        let sample = Instant::now();
+        black_box(f());
+        samples.push(sample.elapsed());
+    }
+    Stats::from_samples(samples)
+}
+
+/// How one phase went: its timings, unless it never finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timing {
+    Measured(Stats),
+    TimedOut,
+    Panicked,
+}
+
+impl Timing {
+    pub fn median(&self) -> Option<Duration> {
+        match self {
+            Timing::Measured(stats) => Some(stats.median),
+            Timing::TimedOut | Timing::Panicked => None,
+        }
+    }
+}
+
+/// Formats as the [`Stats`], or as what stopped the phase, honouring width
+/// and alignment.
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Timing::Measured(stats) => stats.fmt(f),
+            Timing::TimedOut => f.pad("timed out"),
+            Timing::Panicked => f.pad("panicked"),
+        }
+    }
+}
+
+/// Timings for every phase of one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Report {
+    pub parse: Timing,
+    pub part_one: Timing,
+    pub part_two: Timing,
+}
+
+impl Report {
+    /// The sum of the median timings, if every phase finished.
+    pub fn total(&self) -> Option<Duration> {
+        [self.parse, self.part_one, self.part_two]
+            .iter()
+            .map(Timing::median)
+            .sum()
+    }
+}
+
+/// Runs `phase` on a thread of its own, giving up on it after `timeout`. A
+/// phase that times out is left running in the background until the runner
+/// exits, keeping a core busy, so phases timed after it may run slower.
+fn on_worker(
+    timeout: Duration,
+    phase: impl FnOnce() -> Result<Stats, ParseError> + Send + 'static,
+) -> Result<Timing, ParseError> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(phase());
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(stats) => stats.map(Timing::Measured),
+        Err(mpsc::RecvTimeoutError::Timeout) => Ok(Timing::TimedOut),
+        // The phase dropped the sender without sending, by panicking.
+        Err(mpsc::RecvTimeoutError::Disconnected) => Ok(Timing::Panicked),
+    }
+}
+
+pub(crate) fn bench<S: Solution + 'static>(
+    input: &str,
+    options: &Options,
+) -> Result<Report, ParseError> {
+    let input: Arc<str> = input.into();
+    // Each phase parses the input for itself, so that a part that never
+    // finishes cannot hold up the others.
+    let phase = |measured: fn(&str, &Options) -> Result<Stats, ParseError>| {
+        let (input, options) = (input.clone(), options.clone());
+        on_worker(options.timeout, move || measured(&input, &options))
+    };
+    let parse = phase(|input, options| {
+        S::parse(input)?;
+        Ok(measure(options, || S::parse(black_box(input))))
+    })?;
+    if parse.median().is_none() {
+        // The parts cannot get any further than parsing did.
+        return Ok(Report {
+            parse,
+            part_one: parse,
+            part_two: parse,
+        });
+    }
+    Ok(Report {
+        parse,
+        part_one: phase(|input, options| {
+            let parsed = S::parse(input)?;
+            Ok(measure(options, || S::part_one(black_box(&parsed))))
+        })?,
+        part_two: phase(|input, options| {
+            let parsed = S::parse(input)?;
+            Ok(measure(options, || S::part_two(black_box(&parsed))))
+        })?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(5));
+        assert_eq!(stats.to_string(), "3.0ms (1.0ms–5.0ms)");
+    }
+
+    #[test]
+    fn test_human() {
+        assert_eq!(human(Duration::from_nanos(999)), "999ns");
+        assert_eq!(human(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(human(Duration::from_millis(250)), "250.0ms");
+        assert_eq!(human(Duration::from_millis(2_500)), "2.50s");
+    }
+
+    #[test]
+    fn test_measure_counts_runs() {
+        let options = Options {
+            warmup: 2,
+            samples: 3,
+            time_limit: Duration::from_secs(60),
+            ..Options::default()
+        };
+        let mut runs = 0;
+        let stats = measure(&options, || runs += 1);
+        assert_eq!((runs, stats.samples), (5, 3));
+
+        let options = Options {
+            time_limit: Duration::ZERO,
+            ..options
+        };
+        let mut runs = 0;
+        let stats = measure(&options, || runs += 1);
+        assert_eq!((runs, stats.samples), (1, 1));
+    }
+
+    #[test]
+    fn test_on_worker_times_out() {
+        let timing = on_worker(Duration::from_millis(10), || {
+            thread::sleep(Duration::from_secs(60));
+            unreachable!()
+        });
+        assert_eq!(timing, Ok(Timing::TimedOut));
+
+        let stats = Stats::from_samples(vec![Duration::from_millis(1)]);
+        let timing = on_worker(Duration::from_secs(60), move || Ok(stats));
+        assert_eq!(timing, Ok(Timing::Measured(stats)));
+
+        let report = Report {
+            parse: Timing::Measured(stats),
+            part_one: Timing::Measured(stats),
+            part_two: Timing::TimedOut,
+        };
+        assert_eq!(report.total(), None);
+        assert_eq!(format!("{:<12}|", Timing::TimedOut), "timed out   |");
+    }
+}
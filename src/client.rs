@@ -0,0 +1,432 @@
+//! Talking to the Advent of Code website, for `aoc fetch` and friends.
+//!
+//! Requests are authenticated with the `session` cookie of a logged-in
+//! browser. It is read from [`SESSION_VAR`] if set, and otherwise from the
+//! config file at `$AOC_CONFIG`, `$XDG_CONFIG_HOME/aoc/config.toml` or
+//! `~/.config/aoc/config.toml`:
+//!
+//! ```toml
+//! session = "53616c7465645f5f..."
+//! # Optional: appended to the User-Agent so the site can get in touch.
+//! contact = "you@example.com"
+//! # Optional: somewhere other than https://adventofcode.com, e.g. a stub.
+//! base_url = "http://127.0.0.1:8080"
+//! ```
+
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::input;
+
+/// Environment variable holding the session cookie, overriding the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable holding the base URL, overriding the config file.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Environment variable naming the config file.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the site, as its automation guidelines ask.
+const USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (Advent of Code solutions runner, written in Rust)"
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub contact: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            contact: None,
+        }
+    }
+}
+
+impl FromStr for Config {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: toml::Table = s
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_string())?;
+        let mut config = Config::default();
+        for (key, value) in table {
+            let toml::Value::String(value) = value else {
+                return Err(format!("{key} should be a string"));
+            };
+            match key.as_str() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                "contact" => config.contact = Some(value),
+                _ => return Err(format!("unknown key {key:?}")),
+            }
+        }
+        Ok(config)
+    }
+}
+
+impl Config {
+    /// Where the config file is looked for, if anywhere.
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os(CONFIG_VAR).filter(|path| !path.is_empty()) {
+            return Some(path.into());
+        }
+        let dir = match env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+            Some(dir) => PathBuf::from(dir),
+            None => PathBuf::from(env::var_os("HOME")?).join(".config"),
+        };
+        Some(dir.join("aoc/config.toml"))
+    }
+
+    /// Reads the config file, if there is one, and applies the overrides
+    /// from the environment.
+    pub fn load() -> Result<Config, Error> {
+        let mut config = match Config::path() {
+            Some(path) => match fs::read_to_string(&path) {
+                Ok(text) => text
+                    .parse()
+                    .map_err(|message| Error::Config { path, message })?,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => Config::default(),
+                Err(err) => {
+                    return Err(Error::Config {
+                        path,
+                        message: err.to_string(),
+                    })
+                }
+            },
+            None => Config::default(),
+        };
+        if let Some(session) = env::var(SESSION_VAR).ok().filter(|s| !s.is_empty()) {
+            config.session = Some(session);
+        }
+        if let Some(base_url) = env::var(BASE_URL_VAR).ok().filter(|s| !s.is_empty()) {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Config {
+        path: PathBuf,
+        message: String,
+    },
+    NoSession,
+    /// The site answered, but not with success.
+    Status {
+        url: String,
+        status: u16,
+        message: String,
+    },
+    /// The site could not be reached.
+    Transport {
+        url: String,
+        message: String,
+    },
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Config { path, message } => write!(f, "{}: {message}", path.display()),
+            Error::NoSession => write!(
+                f,
+                "no session cookie: set {SESSION_VAR} or `session` in {}",
+                Config::path().map_or("the config file".to_string(), |p| p.display().to_string())
+            ),
+            Error::Status {
+                url,
+                status,
+                message,
+            } => write!(f, "{url}: {status} {message}"),
+            Error::Transport { url, message } => write!(f, "{url}: {message}"),
+            Error::Io { path, error } => write!(f, "{}: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Whether [`Client::download_input`] had to download anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    AlreadyPresent,
+}
+
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let user_agent = match &config.contact {
+            Some(contact) => format!("{USER_AGENT} contact: {contact}"),
+            None => USER_AGENT.to_string(),
+        };
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&user_agent)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Client { config, agent }
+    }
+
+    /// The full URL of a path on the site, e.g. `/2023/day/5`.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.config.base_url.trim_end_matches('/'))
+    }
+
+    fn session(&self) -> Result<&str, Error> {
+        self.config.session.as_deref().ok_or(Error::NoSession)
+    }
+
+    /// Sends a request for `path`, e.g. `/2023/day/5/input`, and returns the
+    /// body of a successful response. Requests with a form are POSTed.
+    fn send(
+        &self,
+        path: &str,
+        form: Option<&[(&str, &str)]>,
+        session: Option<&str>,
+    ) -> Result<String, Error> {
+        let url = self.url(path);
+        let request = match form {
+            Some(_) => self.agent.post(&url),
+            None => self.agent.get(&url),
+        };
+        let request = match session {
+            Some(session) => request.set("Cookie", &format!("session={session}")),
+            None => request,
+        };
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        let read = |response: ureq::Response| {
+            response.into_string().map_err(|err| Error::Transport {
+                url: url.clone(),
+                message: err.to_string(),
+            })
+        };
+        match response {
+            Ok(response) => read(response),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = read(response).unwrap_or_default();
+                Err(Error::Status {
+                    url: url.clone(),
+                    status,
+                    message: body.lines().next().unwrap_or_default().trim().to_string(),
+                })
+            }
+            Err(ureq::Error::Transport(err)) => Err(Error::Transport {
+                url: url.clone(),
+                message: err.to_string(),
+            }),
+        }
+    }
+
+    /// Sends an authenticated GET request.
+    pub fn get(&self, path: &str) -> Result<String, Error> {
+        self.send(path, None, Some(self.session()?))
+    }
+
+    /// Sends an authenticated POST request with a form.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        self.send(path, Some(form), Some(self.session()?))
+    }
+
+    /// Fetches a page that anyone can see, sending the session cookie if
+    /// there is one, since logged-in users see more of some pages (such as
+    /// part two of a puzzle they have solved part one of).
+    pub fn page(&self, path: &str) -> Result<String, Error> {
+        self.send(path, None, self.config.session.as_deref())
+    }
+
+    /// Downloads a day's input to `path`, unless it is already there. An
+    /// empty file, as `aoc new` leaves behind, counts as missing.
+    pub fn download_input(&self, year: u16, day: u8, path: &Path) -> Result<Fetched, Error> {
+        if fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+            return Ok(Fetched::AlreadyPresent);
+        }
+        let body = self.get(&format!("/{year}/day/{day}/input"))?;
+
+        // Write to a temporary file first, so that an interrupted download
+        // never leaves a partial input to be mistaken for a complete one.
+        let io_error = |error| Error::Io {
+            path: path.to_path_buf(),
+            error,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let partial = path.with_extension("part");
+        fs::write(&partial, body).map_err(io_error)?;
+        fs::rename(&partial, path).map_err(io_error)?;
+        Ok(Fetched::Downloaded)
+    }
+
+    /// Downloads a day's input to where the runner looks for it by default.
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<(Fetched, PathBuf), Error> {
+        let path = input::path(year, day);
+        Ok((self.download_input(year, day, &path)?, path))
+    }
+}
+
+/// A minimal HTTP server for tests, which answers each connection with the
+/// next canned response and records the requests it received.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    pub struct Server {
+        pub base_url: String,
+        pub requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    pub fn serve(responses: Vec<(u16, &str)>) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_string()))
+            .collect();
+        thread::spawn(move || {
+            for ((status, body), stream) in responses.into_iter().zip(listener.incoming()) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                recorded.lock().unwrap().push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        Server { base_url, requests }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(base_url: &str) -> Client {
+        Client::new(Config {
+            session: Some("cafe".to_string()),
+            base_url: base_url.to_string(),
+            contact: Some("me@example.com".to_string()),
+        })
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-client-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let config: Config = "session = \"cafe\"\ncontact = \"me@example.com\""
+            .parse()
+            .unwrap();
+        assert_eq!(config.session.as_deref(), Some("cafe"));
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert!("sesion = \"cafe\"".parse::<Config>().is_err());
+        assert!("session = 1".parse::<Config>().is_err());
+    }
+
+    #[test]
+    fn test_download_input() {
+        let server = stub::serve(vec![(200, "1 2\n3 4\n")]);
+        let path = temp_path("2023-05.txt");
+        let client = client(&server.base_url);
+
+        assert_eq!(
+            client.download_input(2023, 5, &path).unwrap(),
+            Fetched::Downloaded
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+        assert_eq!(
+            client.download_input(2023, 5, &path).unwrap(),
+            Fetched::AlreadyPresent
+        );
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        let request = requests[0].to_lowercase();
+        assert!(request.starts_with("get /2023/day/5/input "));
+        assert!(request.contains("cookie: session=cafe\r\n"));
+        assert!(request.contains("user-agent: aoc/"));
+        assert!(request.contains("contact: me@example.com"));
+    }
+
+    #[test]
+    fn test_download_input_errors() {
+        let server = stub::serve(vec![(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        )]);
+        let path = temp_path("2023-25.txt");
+        let err = client(&server.base_url)
+            .download_input(2023, 25, &path)
+            .unwrap_err();
+        assert!(matches!(err, Error::Status { status: 404, .. }), "{err}");
+        assert!(!path.exists());
+
+        let client = Client::new(Config {
+            session: None,
+            ..Config::default()
+        });
+        assert!(matches!(
+            client.download_input(2023, 25, &path),
+            Err(Error::NoSession)
+        ));
+    }
+}
@@ -0,0 +1,145 @@
+//! Finding where a deterministic sequence of states starts repeating, so
+//! that a simulation run for a billion steps only has to be run until it
+//! loops.
+//!
+//! A sequence starts from an initial state, and each state after it is a
+//! function of the one before. [`find`] remembers every state in a hash map
+//! and can give back the state at any step; [`brent`] only ever holds two
+//! states, at the cost of running the sequence several times further.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence loops: the states from step `prefix` onwards repeat
+/// every `period` steps, and `prefix` is as small as it can be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`, which is less than
+    /// `prefix + period`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+
+    /// The state at step `n` of the sequence, found by taking
+    /// [`reduce(n)`](Self::reduce) steps from `initial`.
+    pub fn nth<T>(&self, initial: T, mut step: impl FnMut(&T) -> T, n: usize) -> T {
+        (0..self.reduce(n)).fold(initial, |state, _| step(&state))
+    }
+}
+
+/// The states of a sequence up to the point where it loops.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sequence<T> {
+    pub cycle: Cycle,
+    states: Vec<T>,
+}
+
+impl<T> Sequence<T> {
+    /// The state at step `n`, where step 0 is the initial state.
+    pub fn get(&self, n: usize) -> &T {
+        &self.states[self.cycle.reduce(n)]
+    }
+
+    /// The states before the first repeat, in order.
+    pub fn states(&self) -> &[T] {
+        &self.states
+    }
+}
+
+/// Runs the sequence from `initial` until a state repeats, keeping every
+/// state.
+pub fn find<T: Hash + Eq + Clone>(initial: T, mut step: impl FnMut(&T) -> T) -> Sequence<T> {
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+    loop {
+        let next = step(states.last().unwrap());
+        if let Some(&prefix) = seen.get(&next) {
+            let period = states.len() - prefix;
+            return Sequence {
+                cycle: Cycle { prefix, period },
+                states,
+            };
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+}
+
+/// Finds where the sequence from `initial` loops with Brent's algorithm,
+/// which keeps only two states at a time and needs no hashing.
+pub fn brent<T: Eq + Clone>(initial: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // Find the period: the hare runs ahead in ever doubling stretches, with
+    // the tortoise waiting at the start of each, until they meet.
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // Find the prefix: with the hare a period ahead, they first meet where
+    // the cycle starts.
+    let mut tortoise = initial.clone();
+    let mut hare = (0..period).fold(initial, |state, _| step(&state));
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, period }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3, 12, 2, then 7, 23, 10, 16, 27 over and over.
+    fn step(&n: &u64) -> u64 {
+        (n * n + 3) % 29
+    }
+
+    #[test]
+    fn test_find() {
+        let sequence = find(3, step);
+        assert_eq!(
+            sequence.cycle,
+            Cycle {
+                prefix: 3,
+                period: 5
+            }
+        );
+        assert_eq!(sequence.states(), [3, 12, 2, 7, 23, 10, 16, 27]);
+        assert_eq!(*sequence.get(1), 12);
+        assert_eq!(*sequence.get(1_000_000_000), 10);
+    }
+
+    #[test]
+    fn test_brent() {
+        let cycle = brent(3, step);
+        assert_eq!(cycle, find(3, step).cycle);
+        assert_eq!(cycle.nth(3, step, 1_000_000_000), 10);
+        // A sequence that loops straight back to its start.
+        assert_eq!(
+            brent(0_u8, |&n| (n + 1) % 3),
+            Cycle {
+                prefix: 0,
+                period: 3
+            }
+        );
+    }
+}
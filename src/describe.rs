@@ -0,0 +1,379 @@
+//! Puzzle descriptions, as `aoc describe <year> <day>` saves them: the
+//! puzzle's `<article>`s converted to Markdown, and the `<pre><code>` blocks
+//! in them offered up as example fixtures.
+//!
+//! The conversion only understands the handful of tags that puzzle pages
+//! use; anything else is dropped, keeping its text.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    client::{self, Client},
+    examples, input,
+};
+
+/// A piece of HTML: a tag, with its name and attributes, or text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.split_once("-->").map_or("", |(_, rest)| rest);
+        } else if let Some(tag) = rest.strip_prefix('<') {
+            let (tag, after) = tag.split_once('>').unwrap_or((tag, ""));
+            rest = after;
+            tokens.push(match tag.strip_prefix('/') {
+                Some(name) => Token::Close(name.trim()),
+                None => {
+                    let tag = tag.trim_end_matches('/').trim();
+                    let (name, attributes) =
+                        tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                    Token::Open(name, attributes)
+                }
+            });
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+/// The value of a double-quoted attribute, e.g. `href` in `href="/2023"`.
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let (_, value) = attributes.split_once(&format!("{name}=\""))?;
+    Some(value.split_once('"')?.0)
+}
+
+/// Replaces character references such as `&lt;` and `&#39;` with the
+/// characters they stand for.
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let reference = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                name => match name.strip_prefix("#x") {
+                    Some(hex) => char::from_u32(u32::from_str_radix(hex, 16).ok()?)?,
+                    None => char::from_u32(name.strip_prefix('#')?.parse().ok()?)?,
+                },
+            };
+            Some((c, end))
+        });
+        match reference {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// The contents of each `<article>` on a page.
+fn articles(page: &str) -> impl Iterator<Item = &str> {
+    page.split("<article").skip(1).map(|article| {
+        let article = article.split_once('>').map_or("", |(_, body)| body);
+        article
+            .split_once("</article>")
+            .map_or(article, |(body, _)| body)
+    })
+}
+
+/// Converts the puzzle description on a page to Markdown. Links to other
+/// pages on the site are made absolute with `base_url`.
+pub fn markdown(page: &str, base_url: &str) -> String {
+    let mut markdown = String::new();
+    for article in articles(page) {
+        let (mut pre, mut code) = (false, false);
+        let mut links = Vec::new();
+        for token in tokens(article) {
+            match token {
+                Token::Open("h2", _) => markdown.push_str("## "),
+                Token::Close("h2" | "p") => end_block(&mut markdown),
+                Token::Open("pre", _) => {
+                    pre = true;
+                    markdown.push_str("```\n");
+                }
+                Token::Close("pre") => {
+                    pre = false;
+                    if !markdown.ends_with('\n') {
+                        markdown.push('\n');
+                    }
+                    markdown.push_str("```");
+                    end_block(&mut markdown);
+                }
+                Token::Open("code", _) | Token::Close("code") if !pre => {
+                    code = matches!(token, Token::Open(..));
+                    markdown.push('`');
+                }
+                Token::Open("em", _) | Token::Close("em") if !pre && !code => {
+                    markdown.push_str("**");
+                }
+                Token::Open("li", _) => markdown.push_str("- "),
+                Token::Close("li") => markdown.push('\n'),
+                Token::Close("ul") => markdown.push('\n'),
+                Token::Open("br", _) => markdown.push_str("  \n"),
+                Token::Open("a", attributes) => {
+                    let href = attribute(attributes, "href");
+                    if href.is_some() {
+                        markdown.push('[');
+                    }
+                    links.push(href);
+                }
+                Token::Close("a") => {
+                    if let Some(Some(href)) = links.pop() {
+                        let href = decode(href);
+                        let href = match href.strip_prefix('/') {
+                            Some(path) => format!("{}/{path}", base_url.trim_end_matches('/')),
+                            None => href,
+                        };
+                        markdown.push_str(&format!("]({href})"));
+                    }
+                }
+                Token::Text(text) if pre || code => markdown.push_str(&decode(text)),
+                Token::Text(text) => push_text(&mut markdown, &decode(text)),
+                _ => {}
+            }
+        }
+    }
+    markdown.truncate(markdown.trim_end().len());
+    markdown.push('\n');
+    markdown
+}
+
+/// Adds prose, collapsing whitespace as HTML does and escaping the
+/// characters that Markdown would otherwise take as formatting.
+fn push_text(markdown: &mut String, text: &str) {
+    for (i, word) in text.split(char::is_whitespace).enumerate() {
+        let at_line_start = markdown.is_empty() || markdown.ends_with('\n');
+        if i > 0 && !at_line_start && !markdown.ends_with(' ') {
+            markdown.push(' ');
+        }
+        for c in word.chars() {
+            if matches!(c, '\\' | '*' | '_' | '`') {
+                markdown.push('\\');
+            }
+            markdown.push(c);
+        }
+    }
+}
+
+/// Finishes a paragraph, heading or code block with a blank line.
+fn end_block(markdown: &mut String) {
+    markdown.truncate(markdown.trim_end_matches(' ').len());
+    while !markdown.ends_with("\n\n") {
+        markdown.push('\n');
+    }
+}
+
+/// The distinct `<pre><code>` blocks in the puzzle description, which are
+/// usually its examples.
+pub fn code_blocks(page: &str) -> Vec<String> {
+    let mut blocks: Vec<String> = Vec::new();
+    for article in articles(page) {
+        let mut block: Option<String> = None;
+        let mut pre = false;
+        for token in tokens(article) {
+            match token {
+                Token::Open("pre", _) => pre = true,
+                Token::Close("pre") => pre = false,
+                Token::Open("code", _) if pre => block = Some(String::new()),
+                Token::Close("code") => {
+                    if let Some(mut block) = block.take() {
+                        if !block.ends_with('\n') {
+                            block.push('\n');
+                        }
+                        if !blocks.contains(&block) {
+                            blocks.push(block);
+                        }
+                    }
+                }
+                Token::Text(text) => {
+                    if let Some(block) = &mut block {
+                        block.push_str(&decode(text));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    blocks
+}
+
+/// Where a day's description is saved: next to its input, as Markdown.
+pub fn path(year: u16, day: u8) -> PathBuf {
+    input::path(year, day).with_extension("md")
+}
+
+/// What [`describe`] wrote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Saved {
+    pub description: PathBuf,
+    pub examples: Vec<PathBuf>,
+}
+
+/// Downloads a puzzle's description, saving it as Markdown to `description`
+/// and adding its code blocks as candidate examples to `examples_dir`.
+pub fn describe(
+    client: &Client,
+    year: u16,
+    day: u8,
+    description: &Path,
+    examples_dir: &Path,
+) -> Result<Saved, client::Error> {
+    let page = client.page(&format!("/{year}/day/{day}"))?;
+    let markdown = markdown(&page, &client.url(""));
+
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |error: io::Error| client::Error::Io { path, error }
+    };
+    if let Some(dir) = description.parent() {
+        fs::create_dir_all(dir).map_err(io_error(dir))?;
+    }
+    fs::write(description, markdown).map_err(io_error(description))?;
+    let examples =
+        examples::add(examples_dir, &code_blocks(&page)).map_err(io_error(examples_dir))?;
+    Ok(Saved {
+        description: description.to_path_buf(),
+        examples,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{stub, Config};
+    use indoc::indoc;
+
+    const PAGE: &str = indoc! {r#"
+        <!DOCTYPE html>
+        <html><body>
+        <main>
+        <article class="day-desc"><h2>--- Day 19: Aplenty ---</h2><p>The Elves of Gear Island are already waiting for you. <span title="Easter egg">Each</span> part is rated in each of <em>four categories</em>:</p>
+        <ul>
+        <li><code>x</code>: E<em>x</em>tremely cool looking</li>
+        <li><code>a</code>: <em>A</em>erodynamic</li>
+        </ul>
+        <p>For example:</p>
+        <pre><code>px{a&lt;2006:qkq,m&gt;2090:A,rfg}
+        pv{a&gt;1716:R,A}
+        </code></pre>
+        <p>Adding those gives <code><em>19114</em></code>. See <a href="/2023/day/18">yesterday</a> or <a href="https://example.com/a_b">the *rules*</a>.</p>
+        </article>
+        <p>Your puzzle answer was <code>362930</code>.</p>
+        <article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Again:</p>
+        <pre><code>px{a&lt;2006:qkq,m&gt;2090:A,rfg}
+        pv{a&gt;1716:R,A}
+        </code></pre>
+        </article>
+        </main>
+        </body></html>
+    "#};
+
+    #[test]
+    fn test_markdown() {
+        assert_eq!(
+            markdown(PAGE, "https://adventofcode.com"),
+            indoc! {r"
+                ## --- Day 19: Aplenty ---
+
+                The Elves of Gear Island are already waiting for you. Each part is rated in each of **four categories**:
+
+                - `x`: E**x**tremely cool looking
+                - `a`: **A**erodynamic
+
+                For example:
+
+                ```
+                px{a<2006:qkq,m>2090:A,rfg}
+                pv{a>1716:R,A}
+                ```
+
+                Adding those gives `19114`. See [yesterday](https://adventofcode.com/2023/day/18) or [the \*rules\*](https://example.com/a_b).
+
+                ## --- Part Two ---
+
+                Again:
+
+                ```
+                px{a<2006:qkq,m>2090:A,rfg}
+                pv{a>1716:R,A}
+                ```
+            "}
+        );
+    }
+
+    #[test]
+    fn test_code_blocks() {
+        assert_eq!(
+            code_blocks(PAGE),
+            ["px{a<2006:qkq,m>2090:A,rfg}\npv{a>1716:R,A}\n"]
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            decode("a &lt;&amp;&gt; b &#39;c&#x27; & d"),
+            "a <&> b 'c' & d"
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        let server = stub::serve(vec![(200, PAGE)]);
+        let client = Client::new(Config {
+            session: None,
+            base_url: server.base_url.clone(),
+            contact: None,
+        });
+        let dir = std::env::temp_dir().join(format!("aoc-describe-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let saved = describe(
+            &client,
+            2023,
+            19,
+            &dir.join("2023-19.md"),
+            &dir.join("examples"),
+        )
+        .unwrap();
+        assert!(fs::read_to_string(&saved.description)
+            .unwrap()
+            .starts_with("## --- Day 19: Aplenty ---\n"));
+        assert_eq!(
+            saved.examples,
+            [dir.join("examples/01.txt"), dir.join("examples/01.toml")]
+        );
+
+        let requests = server.requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /2023/day/19 "));
+        assert!(!requests[0].to_lowercase().contains("cookie:"));
+    }
+}
@@ -0,0 +1,268 @@
+use std::{fmt, str::FromStr};
+
+use crate::point::Point;
+
+/// A compass direction on a grid where north is up, i.e. towards negative `y`.
+///
+/// The diagonals are there for puzzles that move or look in eight directions;
+/// puzzles that only move orthogonally use [`Cardinal`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+use Direction::*;
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [North, East, South, West];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest,
+    ];
+
+    pub fn is_cardinal(self) -> bool {
+        matches!(self, North | East | South | West)
+    }
+
+    /// The offset of one step in this direction.
+    pub fn offset(self) -> Point {
+        match self {
+            North => Point::new(0, -1),
+            NorthEast => Point::new(1, -1),
+            East => Point::new(1, 0),
+            SouthEast => Point::new(1, 1),
+            South => Point::new(0, 1),
+            SouthWest => Point::new(-1, 1),
+            West => Point::new(-1, 0),
+            NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Rotates clockwise by `eighths` eighths of a turn; negative values turn
+    /// counterclockwise.
+    pub fn rotate(self, eighths: i32) -> Self {
+        Direction::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_around(self) -> Self {
+        self.rotate(4)
+    }
+}
+
+/// One of the four directions of orthogonal movement, for puzzles that never
+/// go diagonally: matching on one needs no arms for the diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Cardinal {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Cardinal {
+    /// All four, clockwise from north.
+    pub const ALL: [Cardinal; 4] = [
+        Cardinal::North,
+        Cardinal::East,
+        Cardinal::South,
+        Cardinal::West,
+    ];
+
+    /// The offset of one step in this direction.
+    pub fn offset(self) -> Point {
+        Direction::from(self).offset()
+    }
+
+    /// Rotates clockwise by `quarters` quarter turns; negative values turn
+    /// counterclockwise.
+    pub fn rotate(self, quarters: i32) -> Self {
+        Cardinal::ALL[(self as i32 + quarters).rem_euclid(4) as usize]
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(-1)
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn turn_around(self) -> Self {
+        self.rotate(2)
+    }
+}
+
+impl From<Cardinal> for Direction {
+    fn from(cardinal: Cardinal) -> Self {
+        match cardinal {
+            Cardinal::North => North,
+            Cardinal::East => East,
+            Cardinal::South => South,
+            Cardinal::West => West,
+        }
+    }
+}
+
+impl TryFrom<Direction> for Cardinal {
+    /// The diagonal direction, which has no cardinal equivalent.
+    type Error = Direction;
+
+    fn try_from(direction: Direction) -> Result<Self, Self::Error> {
+        match direction {
+            North => Ok(Cardinal::North),
+            East => Ok(Cardinal::East),
+            South => Ok(Cardinal::South),
+            West => Ok(Cardinal::West),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+/// Written as the [`Direction`] is, e.g. `N`.
+impl fmt::Display for Cardinal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Direction::from(*self).fmt(f)
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            North => "N",
+            NorthEast => "NE",
+            East => "E",
+            SouthEast => "SE",
+            South => "S",
+            SouthWest => "SW",
+            West => "W",
+            NorthWest => "NW",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(String);
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid direction {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    /// Accepts `U`/`D`/`L`/`R`, `N`/`E`/`S`/`W`, `^`/`v`/`<`/`>` and arrows.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | 'N' | '^' | '↑' => Ok(North),
+            'R' | 'E' | '>' | '→' => Ok(East),
+            'D' | 'S' | 'v' | '↓' => Ok(South),
+            'L' | 'W' | '<' | '←' => Ok(West),
+            '↗' => Ok(NorthEast),
+            '↘' => Ok(SouthEast),
+            '↙' => Ok(SouthWest),
+            '↖' => Ok(NorthWest),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    /// Accepts anything [`Direction::try_from`] does, plus `NE`, `SE`, `SW`
+    /// and `NW`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(c), None, None) => Direction::try_from(c),
+            _ => match s {
+                "NE" => Ok(NorthEast),
+                "SE" => Ok(SouthEast),
+                "SW" => Ok(SouthWest),
+                "NW" => Ok(NorthWest),
+                _ => Err(ParseDirectionError(s.to_string())),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+        assert_eq!(West.turn_right(), North);
+        assert_eq!(NorthEast.turn_right(), SouthEast);
+        assert_eq!(SouthWest.turn_around(), NorthEast);
+        assert_eq!(North.rotate(-1), NorthWest);
+    }
+
+    #[test]
+    fn test_cardinal() {
+        for cardinal in Cardinal::ALL {
+            let direction = Direction::from(cardinal);
+            assert_eq!(Cardinal::try_from(direction), Ok(cardinal));
+            assert_eq!(cardinal.offset(), direction.offset());
+            assert_eq!(Direction::from(cardinal.turn_left()), direction.turn_left());
+            assert_eq!(
+                Direction::from(cardinal.turn_around()),
+                direction.turn_around()
+            );
+        }
+        assert_eq!(Cardinal::try_from(NorthEast), Err(NorthEast));
+        assert_eq!(Cardinal::West.to_string(), "W");
+    }
+
+    #[test]
+    fn test_offsets_cancel_out() {
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset() + direction.turn_around().offset(),
+                Point::ORIGIN
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        for s in ["U", "N", "^", "↑"] {
+            assert_eq!(s.parse(), Ok(North));
+        }
+        for s in ["D", "S", "v", "↓"] {
+            assert_eq!(s.parse(), Ok(South));
+        }
+        assert_eq!("NW".parse(), Ok(NorthWest));
+        assert_eq!(Direction::try_from('>'), Ok(East));
+        assert!("X".parse::<Direction>().is_err());
+        assert!("NNE".parse::<Direction>().is_err());
+    }
+}
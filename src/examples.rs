@@ -0,0 +1,208 @@
+//! The worked examples from the puzzle descriptions, kept as fixtures in
+//! `examples/<year>-<day>/`. Each example is an input `NN.txt` with its
+//! expected answers in `NN.toml`:
+//!
+//! ```toml
+//! part_one = 16
+//!
+//! [params]
+//! steps = 6
+//! ```
+//!
+//! Either part may be left out, since many examples only illustrate one of
+//! them. The optional `[params]` table holds [`Params`] for puzzles whose
+//! examples use different values from the real input.
+//!
+//! The build script generates a test for every example and part, named like
+//! `year2023::day21::example_01_part_one`.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{answers, answers::Error, Answer, Params, Part};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: [Option<Answer>; 2],
+    pub params: Params,
+}
+
+impl Example {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        self.answers[part as usize].as_ref()
+    }
+}
+
+/// The expected answers for a new example, left commented out so that no
+/// test is generated until they are filled in.
+pub const ANSWERS_TEMPLATE: &str = "\
+# Uncomment each part's answer once known to generate a test for it.
+# part_one = 0
+# part_two = 0
+";
+
+/// The directory holding a day's examples.
+pub fn dir(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(format!("{year}-{day:02}"))
+}
+
+fn parse_expected(text: &str) -> Result<([Option<Answer>; 2], Params), String> {
+    let mut table: toml::Table = text
+        .parse()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+    let mut params = Params::default();
+    match table.remove("params") {
+        Some(toml::Value::Table(values)) => {
+            for (name, value) in values {
+                let value = value
+                    .as_integer()
+                    .ok_or_else(|| format!("[params] {name} should be an integer"))?;
+                params.insert(name, value);
+            }
+        }
+        Some(_) => return Err("params should be a table".to_string()),
+        None => {}
+    }
+    Ok((answers::parts_from_table(table)?, params))
+}
+
+/// Loads the example called `name` (e.g. `01`) for a day.
+pub fn load(year: u16, day: u8, name: &str) -> Result<Example, Error> {
+    let dir = dir(year, day);
+    let read = |path: PathBuf| {
+        fs::read_to_string(&path).map_err(|e| Error {
+            path,
+            message: e.to_string(),
+        })
+    };
+    let input = read(dir.join(format!("{name}.txt")))?;
+    let path = dir.join(format!("{name}.toml"));
+    let (answers, params) =
+        parse_expected(&read(path.clone())?).map_err(|message| Error { path, message })?;
+    Ok(Example {
+        input,
+        answers,
+        params,
+    })
+}
+
+/// Adds candidate examples to `dir`, skipping any that are already there,
+/// and returns the paths it wrote. Each gets the next free number, or takes
+/// the place of an empty `NN.txt` such as `aoc new` leaves, with an
+/// [`ANSWERS_TEMPLATE`] unless it already has answers.
+pub fn add(dir: &Path, inputs: &[String]) -> io::Result<Vec<PathBuf>> {
+    let mut existing = Vec::new();
+    for entry in fs::read_dir(dir).into_iter().flatten() {
+        let path = entry?.path();
+        let number = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.parse::<u32>().ok())
+            .filter(|_| path.extension().is_some_and(|ext| ext == "txt"));
+        if let Some(number) = number {
+            existing.push((number, fs::read_to_string(&path)?));
+        }
+    }
+    existing.sort();
+
+    let mut written = Vec::new();
+    for input in inputs {
+        if existing
+            .iter()
+            .any(|(_, text)| text.trim_end() == input.trim_end())
+        {
+            continue;
+        }
+        let number = match existing.iter_mut().find(|(_, text)| text.trim().is_empty()) {
+            Some((number, text)) => {
+                text.clone_from(input);
+                *number
+            }
+            None => {
+                let number = existing.last().map_or(1, |(number, _)| number + 1);
+                existing.push((number, input.clone()));
+                number
+            }
+        };
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!("{number:02}.txt"));
+        fs::write(&path, input)?;
+        written.push(path);
+        let answers = dir.join(format!("{number:02}.toml"));
+        if !answers.exists() {
+            fs::write(&answers, ANSWERS_TEMPLATE)?;
+            written.push(answers);
+        }
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    /// Runs one part of an example through the registry, as the runner would.
+    fn check(year: u16, day: u8, name: &str, part: Part) {
+        let example = load(year, day, name).unwrap();
+        let solution = crate::days()
+            .find(|d| (d.year, d.day) == (year, day))
+            .unwrap_or_else(|| panic!("{year}-{day:02} is not registered"));
+        let answer = solution
+            .solve_part(&example.input, part, &example.params)
+            .unwrap_or_else(|e| panic!("{e}"));
+        assert_eq!(
+            Some(&answer),
+            example.answer(part),
+            "{year}-{day:02} example {name}, {part}"
+        );
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+
+    #[test]
+    fn test_parse_expected() {
+        let (answers, params) = parse_expected(indoc! {"
+            part_two = 1030
+
+            [params]
+            expansion = 10
+        "})
+        .unwrap();
+        assert_eq!(answers, [None, Some(Answer::from(1030))]);
+        assert_eq!(params.get("expansion"), Some(10));
+        assert!(parse_expected("part_one = 1\n[params]\nname = \"x\"").is_err());
+    }
+
+    #[test]
+    fn test_add() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("01.txt"), "").unwrap();
+        fs::write(dir.join("01.toml"), "part_one = 1\n").unwrap();
+
+        let inputs = ["1 2\n".to_string(), "3 4\n".to_string()];
+        let written = add(&dir, &inputs).unwrap();
+        assert_eq!(
+            written,
+            [dir.join("01.txt"), dir.join("02.txt"), dir.join("02.toml")]
+        );
+        assert_eq!(fs::read_to_string(dir.join("02.txt")).unwrap(), "3 4\n");
+        assert_eq!(
+            fs::read_to_string(dir.join("01.toml")).unwrap(),
+            "part_one = 1\n"
+        );
+
+        let inputs = ["3 4\n".to_string(), "5 6\n".to_string()];
+        assert_eq!(
+            add(&dir, &inputs).unwrap(),
+            [dir.join("03.txt"), dir.join("03.toml")]
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{direction::Direction, parse::ParseError, point::Point};
+
+/// A rectangular grid of cells, stored flat in row-major order.
+///
+/// Cells are addressed by [`Point`], with `x` the column and `y` the row.
+/// Indexing with `grid[point]` panics outside the grid; [`Grid::get`] is the
+/// checked alternative.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_vec(width, height, vec![fill; width * height])
+    }
+
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid cells must fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line and one cell per character, with `f` turning
+    /// each character into a cell, or rejecting it by returning `None`. A
+    /// grid with no cells is an error, as no solution has a use for one.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.lines() {
+            let len = cells.len();
+            for (i, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    ParseError::new(&line[i..i + c.len_utf8()], format!("unexpected {c:?}"))
+                })?;
+                cells.push(cell);
+            }
+            let w = *width.get_or_insert(cells.len() - len);
+            if cells.len() - len != w {
+                return Err(ParseError::new(
+                    line,
+                    format!("expected a row of width {w}"),
+                ));
+            }
+            height += 1;
+        }
+        if cells.is_empty() {
+            return Err(ParseError::end_of(input, "expected a grid"));
+        }
+        Ok(Grid::from_vec(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (0..self.width as i64).contains(&p.x) && (0..self.height as i64).contains(&p.y)
+    }
+
+    fn offset(&self, p: Point) -> Option<usize> {
+        if self.contains(p) {
+            Some(p.y as usize * self.width + p.x as usize)
+        } else {
+            None
+        }
+    }
+
+    fn point(&self, offset: usize) -> Point {
+        Point::new((offset % self.width) as i64, (offset / self.width) as i64)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    pub fn swap(&mut self, a: Point, b: Point) {
+        let (a, b) = (self.offset(a).unwrap(), self.offset(b).unwrap());
+        self.cells.swap(a, b);
+    }
+
+    /// Every point in the grid, in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height * width).map(move |i| Point::new((i % width) as i64, (i / width) as i64))
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Every cell together with its position, in row-major order.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The orthogonal neighbours of `p` that lie inside the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::CARDINAL
+            .iter()
+            .map(move |d| p + d.offset())
+            .filter(|&q| self.contains(q))
+    }
+
+    /// The orthogonal and diagonal neighbours of `p` that lie inside the grid.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .iter()
+            .map(move |d| p + d.offset())
+            .filter(|&q| self.contains(q))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> slice::Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// The first point, in row-major order, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// The first point, in row-major order, whose cell satisfies `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(predicate).map(|i| self.point(i))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Builds a `width` by `height` grid whose cell at `p` is `self[f(p)]`.
+    fn remap(&self, width: usize, height: usize, f: impl Fn(i64, i64) -> Point) -> Self {
+        let cells = (0..height as i64)
+            .flat_map(|y| (0..width as i64).map(move |x| (x, y)))
+            .map(|(x, y)| self[f(x, y)].clone())
+            .collect();
+        Grid::from_vec(width, height, cells)
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| Point::new(y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let h = self.height as i64;
+        self.remap(self.height, self.width, |x, y| Point::new(y, h - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let w = self.width as i64;
+        self.remap(self.height, self.width, |x, y| Point::new(w - 1 - y, x))
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let w = self.width as i64;
+        self.remap(self.width, self.height, |x, y| Point::new(w - 1 - x, y))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let h = self.height as i64;
+        self.remap(self.width, self.height, |x, y| Point::new(x, h - 1 - y))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.offset(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{p} is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.offset(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{p} is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {"
+        abc
+        def
+    "};
+
+    fn example() -> Grid<char> {
+        Grid::parse(EXAMPLE, Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.to_string(), EXAMPLE);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Grid::parse("ab\nc\n", Some).unwrap_err();
+        assert_eq!(error.text, "c");
+        let error = Grid::parse("ab\ncd\n", |c| (c != 'd').then_some(c)).unwrap_err();
+        assert_eq!(error.text, "d");
+        let error = Grid::parse("", Some).unwrap_err();
+        assert_eq!(error.message, "expected a grid");
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = example();
+        assert_eq!(grid.get(Point::new(0, 1)), Some(&'d'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        let n4: Vec<_> = grid
+            .neighbours4(Point::new(0, 0))
+            .map(|p| grid[p])
+            .collect();
+        assert_eq!(n4, vec!['b', 'd']);
+        let n8: Vec<_> = grid
+            .neighbours8(Point::new(1, 0))
+            .map(|p| grid[p])
+            .collect();
+        assert_eq!(n8, vec!['c', 'f', 'e', 'd', 'a']);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = example();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = example();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = example();
+        assert_eq!(grid.find(&'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+    }
+}
@@ -0,0 +1,424 @@
+//! Pictures of solutions: grids of cells as PPM or PNG rasters, and polygon
+//! outlines as SVG.
+//!
+//! A grid becomes a [`Raster`] with each cell drawn as a square of `scale`
+//! pixels in the colour its value has in a [`Palette`] (or any function from
+//! cells to colours). Polygons are written as vectors instead, since their
+//! coordinates can be far too large to rasterise.
+
+use std::{
+    collections::HashMap,
+    fs,
+    hash::Hash,
+    io::{self, Write},
+    path::Path,
+    time::Duration,
+};
+
+use flate2::{write::ZlibEncoder, Compression};
+
+use crate::{grid::Grid, point::Point};
+
+/// A colour as red, green and blue.
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// The colours of cell values, with a default for any other value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette<T: Hash + Eq> {
+    colours: HashMap<T, Rgb>,
+    default: Rgb,
+}
+
+impl<T: Hash + Eq> Palette<T> {
+    pub fn new(default: Rgb) -> Self {
+        Palette {
+            colours: HashMap::new(),
+            default,
+        }
+    }
+
+    pub fn with(mut self, value: T, colour: Rgb) -> Self {
+        self.colours.insert(value, colour);
+        self
+    }
+
+    pub fn colour(&self, value: &T) -> Rgb {
+        self.colours.get(value).copied().unwrap_or(self.default)
+    }
+}
+
+/// A colour on a scale from dark blue at 0.0 through red to pale yellow at
+/// 1.0, for heatmaps. Values outside that range are clamped.
+pub fn heat(t: f64) -> Rgb {
+    const STOPS: [Rgb; 4] = [[20, 20, 60], [140, 30, 90], [230, 80, 30], [255, 240, 160]];
+    let t = t.clamp(0.0, 1.0) * (STOPS.len() - 1) as f64;
+    let i = (t as usize).min(STOPS.len() - 2);
+    let f = t - i as f64;
+    let (a, b) = (STOPS[i], STOPS[i + 1]);
+    [0, 1, 2].map(|c| (a[c] as f64 + (b[c] as f64 - a[c] as f64) * f).round() as u8)
+}
+
+/// An image as rows of pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Raster {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Raster {
+    /// Draws each cell of `grid` as a `scale` by `scale` square in the colour
+    /// `colour` gives for it.
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, colour: impl Fn(&T) -> Rgb) -> Self {
+        let (width, height) = (grid.width() * scale, grid.height() * scale);
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in grid.rows() {
+            let colours: Vec<Rgb> = row.iter().map(&colour).collect();
+            for _ in 0..scale {
+                for &c in &colours {
+                    pixels.extend(std::iter::repeat_n(c, scale));
+                }
+            }
+        }
+        Raster {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Writes a binary PPM (`P6`), the simplest format most viewers read.
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(self.pixels.as_flattened())
+    }
+
+    /// The image data of a PNG: each row, preceded by its filter type (here
+    /// none), compressed with zlib.
+    fn compress(&self) -> io::Result<Vec<u8>> {
+        let mut data = ZlibEncoder::new(Vec::new(), Compression::default());
+        for row in self.pixels.chunks(self.width.max(1)) {
+            data.write_all(&[0])?;
+            data.write_all(row.as_flattened())?;
+        }
+        data.finish()
+    }
+
+    /// Writes an 8-bit RGB PNG.
+    pub fn write_png(&self, mut out: impl Write) -> io::Result<()> {
+        write_png_header(&mut out, self.width, self.height)?;
+        chunk(&mut out, b"IDAT", &self.compress()?)?;
+        chunk(&mut out, b"IEND", &[])
+    }
+
+    /// Saves the image as PNG or PPM, as the extension of `path` says.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut out = Vec::new();
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png") => self.write_png(&mut out)?,
+            Some("ppm") => self.write_ppm(&mut out)?,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{}: expected a .png or .ppm file", path.display()),
+                ))
+            }
+        }
+        fs::write(path, out)
+    }
+}
+
+fn chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let mut crc = crc32fast::Hasher::new();
+    crc.update(kind);
+    crc.update(data);
+    out.write_all(&crc.finalize().to_be_bytes())
+}
+
+fn write_png_header(out: &mut impl Write, width: usize, height: usize) -> io::Result<()> {
+    out.write_all(b"\x89PNG\r\n\x1a\n")?;
+    let mut header = Vec::with_capacity(13);
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // 8 bits per channel, RGB, and the only compression, filter and interlace
+    // methods there are.
+    header.extend([8, 2, 0, 0, 0]);
+    chunk(out, b"IHDR", &header)
+}
+
+/// An animated PNG, built up a frame at a time. Frames are compressed as
+/// they are added, so long animations stay small in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    width: usize,
+    height: usize,
+    delay: Duration,
+    frames: Vec<Vec<u8>>,
+}
+
+impl Animation {
+    /// An empty animation showing each frame for `delay`.
+    pub fn new(delay: Duration) -> Self {
+        Animation {
+            width: 0,
+            height: 0,
+            delay,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Adds a frame, which must be the same size as the first.
+    pub fn push(&mut self, frame: &Raster) -> io::Result<()> {
+        if self.frames.is_empty() {
+            (self.width, self.height) = (frame.width, frame.height);
+        } else if (frame.width, frame.height) != (self.width, self.height) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "a {}x{} frame does not fit a {}x{} animation",
+                    frame.width, frame.height, self.width, self.height
+                ),
+            ));
+        }
+        self.frames.push(frame.compress()?);
+        Ok(())
+    }
+
+    /// Writes an APNG that loops forever. Viewers without APNG support show
+    /// the first frame.
+    pub fn write_png(&self, mut out: impl Write) -> io::Result<()> {
+        write_png_header(&mut out, self.width, self.height)?;
+        let mut control = Vec::with_capacity(8);
+        control.extend((self.frames.len() as u32).to_be_bytes());
+        control.extend(0_u32.to_be_bytes());
+        chunk(&mut out, b"acTL", &control)?;
+
+        let delay = self.delay.as_millis().min(u16::MAX as u128) as u16;
+        let mut sequence = 0_u32;
+        for (i, data) in self.frames.iter().enumerate() {
+            let mut frame = Vec::with_capacity(26);
+            frame.extend(sequence.to_be_bytes());
+            frame.extend((self.width as u32).to_be_bytes());
+            frame.extend((self.height as u32).to_be_bytes());
+            frame.extend([0; 8]);
+            frame.extend(delay.to_be_bytes());
+            frame.extend(1000_u16.to_be_bytes());
+            // Neither dispose of nor blend with the previous frame.
+            frame.extend([0, 0]);
+            chunk(&mut out, b"fcTL", &frame)?;
+            sequence += 1;
+
+            if i == 0 {
+                chunk(&mut out, b"IDAT", data)?;
+            } else {
+                let mut frame_data = Vec::with_capacity(4 + data.len());
+                frame_data.extend(sequence.to_be_bytes());
+                frame_data.extend(data);
+                chunk(&mut out, b"fdAT", &frame_data)?;
+                sequence += 1;
+            }
+        }
+        chunk(&mut out, b"IEND", &[])
+    }
+}
+
+/// A picture of a solution for a write-up, as [`Solution::picture`] draws
+/// it: a raster of a grid, or a vector outline.
+///
+/// [`Solution::picture`]: crate::Solution::picture
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Picture {
+    Raster(Raster),
+    Svg(String),
+}
+
+impl Picture {
+    /// The extension of the format the picture is naturally saved in.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Picture::Raster(_) => "png",
+            Picture::Svg(_) => "svg",
+        }
+    }
+
+    /// Saves the picture to `path`: a raster as PNG or PPM, as the extension
+    /// says, and an outline as SVG.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        match self {
+            Picture::Raster(raster) => raster.save(path),
+            Picture::Svg(_) if path.extension().is_none_or(|ext| ext != "svg") => {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{}: expected a .svg file", path.display()),
+                ))
+            }
+            Picture::Svg(svg) => fs::write(path, svg),
+        }
+    }
+}
+
+fn hex(colour: Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", colour[0], colour[1], colour[2])
+}
+
+/// An SVG of the polygon with the given vertices, scaled so that its longer
+/// side is `size` pixels. The outline is drawn in `stroke` at the same width
+/// whatever the scale, and filled with `fill` if given.
+pub fn polygon_svg(vertices: &[Point], size: u32, stroke: Rgb, fill: Option<Rgb>) -> String {
+    let min_x = vertices.iter().map(|p| p.x).min().unwrap_or(0);
+    let max_x = vertices.iter().map(|p| p.x).max().unwrap_or(0);
+    let min_y = vertices.iter().map(|p| p.y).min().unwrap_or(0);
+    let max_y = vertices.iter().map(|p| p.y).max().unwrap_or(0);
+    let (width, height) = ((max_x - min_x).max(1), (max_y - min_y).max(1));
+    let scale = size as f64 / width.max(height) as f64;
+
+    let points: Vec<String> = vertices
+        .iter()
+        .map(|p| format!("{},{}", p.x, p.y))
+        .collect();
+    format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+            "\n",
+            r#"  <polygon points="{}" fill="{}" stroke="{}" stroke-width="2" vector-effect="non-scaling-stroke"/>"#,
+            "\n</svg>\n"
+        ),
+        (width as f64 * scale).round(),
+        (height as f64 * scale).round(),
+        min_x,
+        min_y,
+        width,
+        height,
+        points.join(" "),
+        fill.map_or("none".to_string(), hex),
+        hex(stroke),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::ZlibDecoder;
+    use std::io::Read;
+
+    fn example() -> Raster {
+        let grid = Grid::parse("#.\n", Some).unwrap();
+        let palette = Palette::new(WHITE).with('#', BLACK);
+        Raster::from_grid(&grid, 2, |c| palette.colour(c))
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut ppm = Vec::new();
+        example().write_ppm(&mut ppm).unwrap();
+        let (header, pixels) = ppm.split_at(11);
+        assert_eq!(header, b"P6\n4 2\n255\n");
+        let row = [BLACK, BLACK, WHITE, WHITE].as_flattened().to_vec();
+        assert_eq!(pixels, [row.clone(), row].concat());
+    }
+
+    #[test]
+    fn test_png() {
+        let mut png = Vec::new();
+        example().write_png(&mut png).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..29], [0, 0, 0, 4, 0, 0, 0, 2, 8, 2, 0, 0, 0]);
+
+        let length = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(&png[37..41], b"IDAT");
+        let mut data = Vec::new();
+        ZlibDecoder::new(&png[41..41 + length])
+            .read_to_end(&mut data)
+            .unwrap();
+        let row = [&[0][..], [BLACK, BLACK, WHITE, WHITE].as_flattened()].concat();
+        assert_eq!(data, [row.clone(), row].concat());
+        assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+    }
+
+    #[test]
+    fn test_animation() {
+        let mut animation = Animation::new(Duration::from_millis(100));
+        animation.push(&example()).unwrap();
+        animation.push(&example()).unwrap();
+        assert!(animation
+            .push(&Raster::from_grid(&Grid::new(1, 1, ()), 1, |_| WHITE))
+            .is_err());
+
+        let mut png = Vec::new();
+        animation.write_png(&mut png).unwrap();
+        let mut chunks = Vec::new();
+        let mut rest = &png[8..];
+        while !rest.is_empty() {
+            let length = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            chunks.push(String::from_utf8(rest[4..8].to_vec()).unwrap());
+            rest = &rest[12 + length..];
+        }
+        assert_eq!(
+            chunks,
+            ["IHDR", "acTL", "fcTL", "IDAT", "fcTL", "fdAT", "IEND"]
+        );
+    }
+
+    #[test]
+    fn test_picture_save() {
+        let dir = std::env::temp_dir().join(format!("aoc-picture-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let svg = Picture::Svg(polygon_svg(&[Point::ORIGIN], 10, BLACK, None));
+        assert_eq!(svg.extension(), "svg");
+        assert!(svg.save(&dir.join("outline.png")).is_err());
+        svg.save(&dir.join("outline.svg")).unwrap();
+
+        let raster = Picture::Raster(example());
+        assert_eq!(raster.extension(), "png");
+        raster.save(&dir.join("grid.png")).unwrap();
+        let png = fs::read(dir.join("grid.png")).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn test_heat() {
+        assert_eq!(heat(0.0), [20, 20, 60]);
+        assert_eq!(heat(1.0), [255, 240, 160]);
+        assert_eq!(heat(2.0), heat(1.0));
+    }
+
+    #[test]
+    fn test_polygon_svg() {
+        let square = [
+            Point::new(0, 0),
+            Point::new(2_000_000, 0),
+            Point::new(2_000_000, 1_000_000),
+            Point::new(0, 1_000_000),
+        ];
+        let svg = polygon_svg(&square, 800, BLACK, Some([255, 0, 0]));
+        assert!(svg.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="800" height="400" viewBox="0 0 2000000 1000000">"#
+        ));
+        assert!(svg.contains(
+            r##"points="0,0 2000000,0 2000000,1000000 0,1000000" fill="#ff0000" stroke="#000000""##
+        ));
+    }
+}
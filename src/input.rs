@@ -0,0 +1,158 @@
+//! Locating and reading puzzle inputs at runtime.
+//!
+//! An input comes from, in order of preference:
+//!
+//! 1. an explicit path given on the command line, or `-` for stdin;
+//! 2. `$AOC_INPUT_DIR/<year>-<day>.txt`, when that variable is set;
+//! 3. `src/input/<year>-<day>.txt` in this repository.
+
+use std::{
+    env,
+    ffi::OsString,
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable naming a directory of `<year>-<day>.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Error {
+    pub source: Source,
+    pub error: io::Error,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.source, self.error)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The file name an input is stored under, e.g. `2023-05.txt`.
+pub fn file_name(year: u16, day: u8) -> String {
+    format!("{year}-{day:02}.txt")
+}
+
+/// The checked-in input for a day, independent of the working directory.
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/input")
+        .join(file_name(year, day))
+}
+
+/// Decides where to read a day's input from, given the command-line argument
+/// (if any) and the value of [`INPUT_DIR_VAR`] (if set).
+pub fn resolve(year: u16, day: u8, arg: Option<&str>, input_dir: Option<OsString>) -> Source {
+    match arg {
+        Some("-") => Source::Stdin,
+        Some(path) => Source::File(PathBuf::from(path)),
+        None => Source::File(stored_path(year, day, input_dir)),
+    }
+}
+
+fn stored_path(year: u16, day: u8, input_dir: Option<OsString>) -> PathBuf {
+    match input_dir {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir).join(file_name(year, day)),
+        _ => default_path(year, day),
+    }
+}
+
+/// Where a day's input is kept when no path is given on the command line,
+/// consulting the environment for [`INPUT_DIR_VAR`]. This is where [`read`]
+/// looks by default and where `aoc fetch` saves downloads.
+pub fn path(year: u16, day: u8) -> PathBuf {
+    stored_path(year, day, env::var_os(INPUT_DIR_VAR))
+}
+
+impl Source {
+    pub fn read(&self) -> Result<String, Error> {
+        let result = match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            Source::File(path) => fs::read_to_string(path),
+        };
+        result.map_err(|error| Error {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+/// Tidies up line endings so that parsers only ever see `\n`: CRLF becomes LF,
+/// and trailing blank lines are collapsed into a single final newline.
+pub fn normalize(input: &str) -> String {
+    let mut input = input.replace("\r\n", "\n");
+    input.truncate(input.trim_end_matches('\n').len());
+    if !input.is_empty() {
+        input.push('\n');
+    }
+    input
+}
+
+/// Reads a day's input from wherever [`resolve`] points, consulting the
+/// environment for [`INPUT_DIR_VAR`].
+pub fn read(year: u16, day: u8, arg: Option<&str>) -> Result<String, Error> {
+    resolve(year, day, arg, env::var_os(INPUT_DIR_VAR)).read()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1 2\r\n3 4\r\n\r\n\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("1 2\n3 4"), "1 2\n3 4\n");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn test_resolve_default() {
+        assert_eq!(
+            resolve(2023, 5, None, None),
+            Source::File(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/input/2023-05.txt"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_input_dir() {
+        assert_eq!(
+            resolve(2023, 5, None, Some("/tmp/alice".into())),
+            Source::File(PathBuf::from("/tmp/alice/2023-05.txt"))
+        );
+        assert_eq!(
+            resolve(2023, 5, None, Some("".into())),
+            resolve(2023, 5, None, None)
+        );
+    }
+
+    #[test]
+    fn test_resolve_argument() {
+        assert_eq!(
+            resolve(2023, 5, Some("bob.txt"), Some("/tmp/alice".into())),
+            Source::File(PathBuf::from("bob.txt"))
+        );
+        assert_eq!(resolve(2023, 5, Some("-"), None), Source::Stdin);
+    }
+}
@@ -0,0 +1,382 @@
+//! Ranges of integers, and sets of them, for puzzles about ranges too large
+//! to go through a number at a time.
+//!
+//! An [`Interval`] is half-open, `start..end`, whatever it was made from:
+//! [`Interval::closed`] and `From<RangeInclusive>` convert an inclusive end
+//! once, up front, so nothing else has to think about it. An [`IntervalSet`]
+//! keeps its intervals sorted, non-empty and coalesced, so that two sets with
+//! the same members are equal.
+
+use std::ops::{Range, RangeInclusive};
+
+use crate::math::Integer;
+
+/// The integers from `start` up to but not including `end`. It is empty if
+/// `end <= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    /// The integers from `first` to `last` inclusive.
+    pub fn closed(first: T, last: T) -> Self {
+        Interval::new(first, last + T::ONE)
+    }
+
+    /// The `len` integers from `start`.
+    pub fn with_len(start: T, len: T) -> Self {
+        Interval::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// How many integers there are in the interval.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+
+    /// The last integer in the interval, if it has any.
+    pub fn last(&self) -> Option<T> {
+        (!self.is_empty()).then(|| self.end - T::ONE)
+    }
+
+    pub fn contains(&self, n: T) -> bool {
+        self.start <= n && n < self.end
+    }
+
+    /// Whether the two intervals have any integer in common.
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The integers in both intervals, which may be empty.
+    pub fn intersection(&self, other: &Interval<T>) -> Interval<T> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The integers in this interval that are not in `other`: those below it
+    /// and those above it, either of which may be empty.
+    pub fn difference(&self, other: &Interval<T>) -> (Interval<T>, Interval<T>) {
+        if other.is_empty() {
+            return (*self, Interval::new(self.end, self.end));
+        }
+        let (below, rest) = self.split_at(other.start);
+        let (_, above) = rest.split_at(other.end);
+        (below, above)
+    }
+
+    /// The integers below `n` and those from `n` on, either of which may be
+    /// empty.
+    pub fn split_at(&self, n: T) -> (Interval<T>, Interval<T>) {
+        if self.is_empty() {
+            return (*self, *self);
+        }
+        let n = n.clamp(self.start, self.end);
+        (Interval::new(self.start, n), Interval::new(n, self.end))
+    }
+}
+
+impl<T: Integer> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Interval::new(range.start, range.end)
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (first, last) = range.into_inner();
+        Interval::closed(first, last)
+    }
+}
+
+/// A set of integers, as the intervals that make it up.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// Sorted, non-empty, and neither overlapping nor touching.
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Sorts `intervals` and merges any that overlap or touch.
+    fn coalesce(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_by_key(|interval| interval.start);
+        let mut coalesced: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match coalesced.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => coalesced.push(interval),
+            }
+        }
+        IntervalSet {
+            intervals: coalesced,
+        }
+    }
+
+    /// The intervals making up the set, in order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers there are in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |len, interval| len + interval.len())
+    }
+
+    pub fn contains(&self, n: T) -> bool {
+        let i = self.intervals.partition_point(|interval| interval.end <= n);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(n))
+    }
+
+    /// The smallest integer in the set.
+    pub fn first(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    /// Adds the integers in `interval` to the set.
+    pub fn insert(&mut self, interval: impl Into<Interval<T>>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval.into());
+        *self = IntervalSet::coalesce(intervals);
+    }
+
+    /// The integers in either set.
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let intervals = self.intervals.iter().chain(&other.intervals).copied();
+        IntervalSet::coalesce(intervals.collect())
+    }
+
+    /// The integers in both sets.
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let both = a.intersection(b);
+            if !both.is_empty() {
+                intervals.push(both);
+            }
+            // Whichever ends first cannot overlap anything more in the other.
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The integers in this set that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let mut others = other.intervals.iter().peekable();
+        for &interval in &self.intervals {
+            let mut rest = interval;
+            while let Some(&b) = others.peek() {
+                if b.end <= rest.start {
+                    others.next();
+                    continue;
+                }
+                if b.start >= rest.end {
+                    break;
+                }
+                let (below, above) = rest.difference(b);
+                if !below.is_empty() {
+                    intervals.push(below);
+                }
+                rest = above;
+                if rest.is_empty() {
+                    break;
+                }
+                others.next();
+            }
+            if !rest.is_empty() {
+                intervals.push(rest);
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The integers in the set below `n`, and those from `n` on.
+    pub fn split_at(&self, n: T) -> (IntervalSet<T>, IntervalSet<T>) {
+        let (mut below, mut above) = (Vec::new(), Vec::new());
+        for interval in &self.intervals {
+            let (low, high) = interval.split_at(n);
+            if !low.is_empty() {
+                below.push(low);
+            }
+            if !high.is_empty() {
+                above.push(high);
+            }
+        }
+        (
+            IntervalSet { intervals: below },
+            IntervalSet { intervals: above },
+        )
+    }
+}
+
+impl<T: Integer, I: Into<Interval<T>>> FromIterator<I> for IntervalSet<T> {
+    fn from_iter<It: IntoIterator<Item = I>>(iter: It) -> Self {
+        IntervalSet::coalesce(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet::coalesce(vec![interval])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::from(3..=7);
+        assert_eq!(interval, Interval::new(3, 8));
+        assert_eq!(interval.len(), 5);
+        assert_eq!(interval.last(), Some(7));
+        assert!(interval.contains(7) && !interval.contains(8));
+        assert_eq!(Interval::new(5_u64, 2).len(), 0);
+        assert_eq!(Interval::new(5_u64, 5).last(), None);
+        assert_eq!(
+            interval.difference(&Interval::new(5, 6)),
+            (Interval::new(3, 5), Interval::new(6, 8))
+        );
+        assert_eq!(
+            interval.split_at(10),
+            (Interval::new(3, 8), Interval::new(8, 8))
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let set: IntervalSet<u64> = [0..3, 3..5, 8..10, 9..12].into_iter().collect();
+        assert_eq!(set.intervals(), [Interval::new(0, 5), Interval::new(8, 12)]);
+        assert_eq!(set.len(), 9);
+        assert!(set.contains(4) && !set.contains(5) && set.contains(11));
+
+        let other = IntervalSet::from(Interval::closed(4, 8));
+        assert_eq!(
+            set.difference(&other).intervals(),
+            [Interval::new(0, 4), Interval::new(9, 12)]
+        );
+        assert_eq!(
+            set.intersection(&other).intervals(),
+            [Interval::new(4, 5), Interval::new(8, 9)]
+        );
+        assert_eq!(set.union(&other).intervals(), [Interval::new(0, 12)]);
+    }
+
+    fn members(set: &IntervalSet<i32>) -> BTreeSet<i32> {
+        set.intervals()
+            .iter()
+            .flat_map(|interval| interval.start..interval.end)
+            .collect()
+    }
+
+    fn set() -> impl Strategy<Value = (IntervalSet<i32>, BTreeSet<i32>)> {
+        prop::collection::vec((-20..40, 0..12), 0..6).prop_map(|intervals| {
+            let intervals: Vec<Interval<i32>> = intervals
+                .into_iter()
+                .map(|(start, len)| Interval::with_len(start, len))
+                .collect();
+            let expected = intervals.iter().flat_map(|i| i.start..i.end).collect();
+            (intervals.into_iter().collect(), expected)
+        })
+    }
+
+    fn assert_normal(set: &IntervalSet<i32>) {
+        for interval in set.intervals() {
+            assert!(!interval.is_empty(), "{set:?} has an empty interval");
+        }
+        for pair in set.intervals().windows(2) {
+            assert!(pair[0].end < pair[1].start, "{set:?} is not coalesced");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_collect((set, expected) in set()) {
+            assert_normal(&set);
+            prop_assert_eq!(members(&set), expected.clone());
+            prop_assert_eq!(set.len() as usize, expected.len());
+            for n in -25..55 {
+                prop_assert_eq!(set.contains(n), expected.contains(&n));
+            }
+        }
+
+        #[test]
+        fn prop_operations((a, a_members) in set(), (b, b_members) in set()) {
+            let union = a.union(&b);
+            assert_normal(&union);
+            prop_assert_eq!(members(&union), &a_members | &b_members);
+
+            let intersection = a.intersection(&b);
+            assert_normal(&intersection);
+            prop_assert_eq!(members(&intersection), &a_members & &b_members);
+
+            let difference = a.difference(&b);
+            assert_normal(&difference);
+            prop_assert_eq!(members(&difference), &a_members - &b_members);
+        }
+
+        #[test]
+        fn prop_split_at((set, expected) in set(), n in -25..55) {
+            let (below, above) = set.split_at(n);
+            assert_normal(&below);
+            assert_normal(&above);
+            prop_assert_eq!(members(&below), expected.range(..n).copied().collect());
+            prop_assert_eq!(members(&above), expected.range(n..).copied().collect());
+        }
+
+        #[test]
+        fn prop_interval(a in -10..10, b in -10..10, c in -10..10, d in -10..10) {
+            let (x, y) = (Interval::new(a, b), Interval::new(c, d));
+            let in_x = |n| x.contains(n);
+            let in_y = |n| y.contains(n);
+            let intersection = x.intersection(&y);
+            let (below, above) = x.difference(&y);
+            for n in -12..12 {
+                prop_assert_eq!(intersection.contains(n), in_x(n) && in_y(n));
+                prop_assert_eq!(below.contains(n) || above.contains(n), in_x(n) && !in_y(n));
+            }
+            prop_assert_eq!(x.overlaps(&y), !intersection.is_empty());
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Advent of Code solutions, one module per puzzle, plus the registry that the
+//! `aoc` runner dispatches on.
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod cycle;
+pub mod describe;
+pub mod direction;
+pub mod examples;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod interval;
+pub mod logging;
+pub mod math;
+pub mod parse;
+pub mod point;
+pub mod record;
+pub mod render;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod year2022;
+pub mod year2023;
+
+pub use parse::ParseError;
+pub use solution::{Answer, Params, Part, Solution};
+
+/// A registered puzzle and the entry point that solves it.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    solve: fn(&str) -> Result<[Answer; 2], ParseError>,
+    solve_part: fn(&str, Part, &Params) -> Result<Answer, ParseError>,
+    bench: fn(&str, &bench::Options) -> Result<bench::Report, ParseError>,
+    picture: fn(&str, Part) -> Result<Option<image::Picture>, ParseError>,
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>(year: u16, day: u8) -> Self {
+        Day {
+            year,
+            day,
+            solve: solve::<S>,
+            solve_part: solve_part::<S>,
+            bench: bench::bench::<S>,
+            picture: picture::<S>,
+        }
+    }
+
+    /// Parses the given puzzle input and answers both parts.
+    ///
+    /// The input's line endings are [normalized](input::normalize) first, and
+    /// parse errors are located within it.
+    pub fn solve(&self, input: &str) -> Result<[Answer; 2], ParseError> {
+        self.with_input(input, self.solve)
+    }
+
+    /// Parses the given puzzle input and answers just one part, with the
+    /// given [`Params`] (which are empty for real inputs).
+    pub fn solve_part(
+        &self,
+        input: &str,
+        part: Part,
+        params: &Params,
+    ) -> Result<Answer, ParseError> {
+        self.with_input(input, |input| (self.solve_part)(input, part, params))
+    }
+
+    /// Times parsing and each part separately, as [`bench::measure`] does.
+    pub fn bench(
+        &self,
+        input: &str,
+        options: &bench::Options,
+    ) -> Result<bench::Report, ParseError> {
+        self.with_input(input, |input| (self.bench)(input, options))
+    }
+
+    /// Parses the given puzzle input and pictures one part's solution, if
+    /// the day has a [picture](Solution::picture) of it.
+    pub fn picture(&self, input: &str, part: Part) -> Result<Option<image::Picture>, ParseError> {
+        self.with_input(input, |input| (self.picture)(input, part))
+    }
+
+    fn with_input<T>(
+        &self,
+        input: &str,
+        f: impl FnOnce(&str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let input = input::normalize(input);
+        f(&input).map_err(|e| e.locate(&input).for_day(self.year, self.day))
+    }
+}
+
+fn solve<S: Solution>(input: &str) -> Result<[Answer; 2], ParseError> {
+    let input = S::parse(input)?;
+    Ok([S::part_one(&input), S::part_two(&input)])
+}
+
+fn solve_part<S: Solution>(input: &str, part: Part, params: &Params) -> Result<Answer, ParseError> {
+    let input = S::parse(input)?;
+    Ok(match part {
+        Part::One => S::part_one_with(&input, params),
+        Part::Two => S::part_two_with(&input, params),
+    })
+}
+
+fn picture<S: Solution>(input: &str, part: Part) -> Result<Option<image::Picture>, ParseError> {
+    Ok(S::picture(&S::parse(input)?, part))
+}
+
+/// Every registered day, ordered by year and then by day.
+pub fn days() -> impl Iterator<Item = &'static Day> {
+    year2022::DAYS.iter().chain(year2023::DAYS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    fn find(year: u16, day: u8) -> &'static Day {
+        days().find(|d| (d.year, d.day) == (year, day)).unwrap()
+    }
+
+    #[test]
+    fn test_solve_normalizes_line_endings() {
+        let input = "0 3 6 9 12 15\r\n1 3 6 10 15 21\r\n10 13 16 21 30 45\r\n\r\n";
+        assert_eq!(find(2023, 9).solve(input), Ok([114.into(), 2.into()]));
+    }
+
+    #[test]
+    fn test_solve_locates_errors() {
+        let input = "Game 1: 3 blue, 4 red\r\nGame 2: 1 purple\r\n";
+        let error = find(2023, 2).solve(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            indoc! {r#"
+                parse error in 2023-02 at line 2, column 11: unknown color "purple"
+                  |
+                2 | Game 2: 1 purple
+                  |           ^^^^^^"#}
+        );
+    }
+}
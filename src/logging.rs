@@ -0,0 +1,120 @@
+//! Logging from solutions, for seeing what they are doing without disturbing
+//! the answers: log output goes to stderr, and is off below warnings unless
+//! asked for.
+//!
+//! Solvers log with the [`log`] macros, and each day's messages have its
+//! module as their target, e.g. `aoc::year2023::day10`. `-v` on the command
+//! line turns on debug messages from every day and `-vv` trace messages too.
+//! `AOC_LOG` takes filters as `RUST_LOG` does for `env_logger`, with days
+//! also written as `<year>-<day>`: `AOC_LOG=2023-10=trace,2023-20=debug`.
+
+use std::env;
+
+use log::LevelFilter;
+
+/// The environment variable holding log filters.
+pub const FILTER_VAR: &str = "AOC_LOG";
+
+/// Rewrites the `<year>-<day>` targets in `filters` as the modules of those
+/// days, leaving everything else as it is.
+fn expand(filters: &str) -> String {
+    let (directives, regex) = match filters.split_once('/') {
+        Some((directives, regex)) => (directives, Some(regex)),
+        None => (filters, None),
+    };
+    let mut expanded: Vec<String> = directives
+        .split(',')
+        .map(|directive| {
+            let (target, level) = match directive.split_once('=') {
+                Some((target, level)) => (target.trim(), Some(level)),
+                None => (directive.trim(), None),
+            };
+            let target = match target.split_once('-') {
+                Some((year, day))
+                    if year.len() == 4
+                        && day.len() == 2
+                        && year.parse::<u16>().is_ok()
+                        && day.parse::<u8>().is_ok() =>
+                {
+                    format!("aoc::year{year}::day{day}")
+                }
+                _ => target.to_string(),
+            };
+            match level {
+                Some(level) => format!("{target}={level}"),
+                None => target,
+            }
+        })
+        .collect();
+    if let Some(regex) = regex {
+        let last = expanded.pop().unwrap_or_default();
+        expanded.push(format!("{last}/{regex}"));
+    }
+    expanded.join(",")
+}
+
+/// A logger to stderr showing warnings, messages from this crate at the
+/// level `verbosity` asks for, and anything `filters` adds.
+pub fn logger(verbosity: u8, filters: Option<&str>) -> env_logger::Logger {
+    let level = match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    let mut builder = env_logger::Builder::new();
+    builder
+        .filter_level(LevelFilter::Warn)
+        .filter_module("aoc", level)
+        .format_timestamp(None);
+    if let Some(filters) = filters {
+        builder.parse_filters(&expand(filters));
+    }
+    builder.build()
+}
+
+/// Installs the logger for `verbosity` and the filters in `AOC_LOG`.
+pub fn init(verbosity: u8) {
+    let filters = env::var(FILTER_VAR).ok();
+    let logger = logger(verbosity, filters.as_deref());
+    log::set_max_level(logger.filter());
+    // Only fails if a logger is already installed, which is then kept.
+    let _ = log::set_boxed_logger(Box::new(logger));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::{Level, Log, Metadata};
+
+    fn enabled(logger: &env_logger::Logger, target: &str, level: Level) -> bool {
+        logger.enabled(&Metadata::builder().target(target).level(level).build())
+    }
+
+    #[test]
+    fn test_expand() {
+        assert_eq!(
+            expand("2023-10=trace,ureq,aoc::client=debug"),
+            "aoc::year2023::day10=trace,ureq,aoc::client=debug"
+        );
+        assert_eq!(expand("2022-01/Pushing"), "aoc::year2022::day01/Pushing");
+        assert_eq!(expand("debug"), "debug");
+    }
+
+    #[test]
+    fn test_logger() {
+        let day10 = "aoc::year2023::day10";
+        let quiet = logger(0, None);
+        assert!(enabled(&quiet, day10, Level::Warn));
+        assert!(!enabled(&quiet, day10, Level::Debug));
+
+        let verbose = logger(1, None);
+        assert!(enabled(&verbose, day10, Level::Debug));
+        assert!(!enabled(&verbose, day10, Level::Trace));
+        assert!(!enabled(&verbose, "ureq", Level::Debug));
+
+        let filtered = logger(1, Some("2023-10=trace,aoc::year2023::day20=off"));
+        assert!(enabled(&filtered, day10, Level::Trace));
+        assert!(!enabled(&filtered, "aoc::year2023::day20", Level::Warn));
+        assert!(enabled(&filtered, "aoc::year2023::day14", Level::Debug));
+    }
+}
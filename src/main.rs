@@ -0,0 +1,712 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, HashMap},
+    env, fmt,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::{mpsc, Arc},
+    thread,
+    time::Duration,
+};
+
+use aoc::{
+    answers, bench,
+    client::{self, Client, Config, Fetched},
+    describe, examples, input, logging, record, scaffold,
+    submit::{self, History, Outcome},
+    Answer, Day, Params, ParseError, Part,
+};
+
+const USAGE: &str = "\
+Usage:
+    aoc [-v | -vv] <command> ...
+
+    aoc run [--timeout <seconds>] <year> <day> [<input>]
+    aoc run [--timeout <seconds>] <year>
+    aoc run [--timeout <seconds>] all
+    aoc run --record <path> <year> <day> [<input>]
+    aoc bench [--samples <n>] [--warmup <n>] [--timeout <seconds>] <year> [<day> [<input>]]
+    aoc bench [--samples <n>] [--warmup <n>] [--timeout <seconds>] all
+    aoc verify [--timeout <seconds>] <year> [<day> [<input>]]
+    aoc verify [--timeout <seconds>] all
+    aoc new <year> <day>
+    aoc fetch <year> <day>
+    aoc submit <year> <day> <part> [<input>]
+    aoc describe <year> <day>
+    aoc picture <year> <day> <part> <path> [<input>]
+
+-v logs debug messages from the solutions to stderr, and -vv trace messages
+too. $AOC_LOG adds filters in env_logger's syntax, with days written as
+<year>-<day>, e.g. AOC_LOG=2023-10=trace.
+
+<input> is a path to the puzzle input, or `-` to read it from stdin. Without
+it, inputs are read from $AOC_INPUT_DIR/<year>-<day>.txt if AOC_INPUT_DIR is
+set, and from src/input/<year>-<day>.txt otherwise.
+
+`run` gives up on a part after --timeout seconds (default 60), and goes on
+with the rest of the days.
+
+`run --record` saves the frames that a day's simulation draws, for each part
+in turn. A <path> ending in .png gets an animated PNG per part, <stem>-part1.png
+and <stem>-part2.png; any other <path> is a directory of numbered PNGs, in
+part1/ and part2/. At most 1000 frames are kept per part.
+
+`bench` times parsing and each part separately, after <n> warm-up runs
+(default 1) and over <n> samples (default 10), and lists the days that did
+not finish first, then the slowest. A phase stops sampling early once it has
+taken 5 seconds, and is given up on as timed out after --timeout seconds
+(default 60). A phase that times out keeps running in the background, which
+can slow down the days timed after it.
+
+`verify` checks each part against the answers recorded in
+answers/<year>.toml, giving up on a part after --timeout seconds (default 60).
+It fails if any recorded answer is not reproduced.
+
+`new` scaffolds a day: its module, registration, an empty input and an empty
+example fixture.
+
+`fetch` downloads a day's input to where `run` looks for it, unless it is
+already there. It needs the session cookie of a logged-in browser, from
+$AOC_SESSION or `session = \"...\"` in ~/.config/aoc/config.toml.
+
+`submit` solves a part (1 or 2) and sends the answer, recording the outcome in
+submissions/<year>.toml. Answers already known to be wrong, or beyond a
+known \"too high\" or \"too low\", are refused without being sent.
+
+`describe` saves the puzzle description as Markdown next to the input, and
+adds its code blocks to examples/<year>-<day>/ as candidate examples.
+
+`picture` saves a picture of a part's solution for a write-up, for the days
+that draw one: a grid as PNG (or PPM, for a <path> ending in .ppm), or an
+outline as SVG. A <path> without an extension gets the picture's own.";
+
+enum Command {
+    Run {
+        record: Option<PathBuf>,
+        timeout: Duration,
+    },
+    Bench(bench::Options),
+    Verify {
+        timeout: Duration,
+    },
+    New,
+    Fetch,
+    Submit,
+    Describe,
+    Picture,
+}
+
+/// Splits the arguments into the command and its remaining positional
+/// arguments, consuming any `--flag <value>` options along the way.
+fn command(args: &[String]) -> Option<(Command, Vec<String>)> {
+    let (command, rest) = args.split_first()?;
+    let mut options = bench::Options::default();
+    let mut timeout = options.timeout;
+    let mut record = None;
+    let mut positional = Vec::new();
+    let mut rest = rest.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--samples" if command == "bench" => options.samples = rest.next()?.parse().ok()?,
+            "--warmup" if command == "bench" => options.warmup = rest.next()?.parse().ok()?,
+            "--timeout" if ["run", "bench", "verify"].contains(&command.as_str()) => {
+                timeout = Duration::from_secs(rest.next()?.parse().ok()?)
+            }
+            "--record" if command == "run" => record = Some(PathBuf::from(rest.next()?)),
+            _ => positional.push(arg.clone()),
+        }
+    }
+    match command.as_str() {
+        "run" => Some((Command::Run { record, timeout }, positional)),
+        "bench" => Some((
+            Command::Bench(bench::Options { timeout, ..options }),
+            positional,
+        )),
+        "verify" => Some((Command::Verify { timeout }, positional)),
+        "new" => Some((Command::New, positional)),
+        "fetch" => Some((Command::Fetch, positional)),
+        "submit" => Some((Command::Submit, positional)),
+        "describe" => Some((Command::Describe, positional)),
+        "picture" => Some((Command::Picture, positional)),
+        _ => None,
+    }
+}
+
+/// The days selected on the command line, and the input override, if any.
+struct Selection<'a> {
+    days: Vec<&'static Day>,
+    input: Option<&'a str>,
+}
+
+fn select(args: &[String]) -> Option<Selection<'_>> {
+    let (days, input) = match args {
+        [all] if all == "all" => (aoc::days().collect(), None),
+        [year] => {
+            let year = year.parse().ok()?;
+            (aoc::days().filter(|d| d.year == year).collect(), None)
+        }
+        [year, day, input @ ..] if input.len() <= 1 => {
+            let (year, day) = (year.parse().ok()?, day.parse().ok()?);
+            let days = aoc::days()
+                .filter(|d| d.year == year && d.day == day)
+                .collect();
+            (days, input.first().map(String::as_str))
+        }
+        _ => return None,
+    };
+    Some(Selection { days, input })
+}
+
+fn run(selection: &Selection, timeout: Duration) -> ExitCode {
+    // Panics are reported in the table, so keep their messages off stderr.
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut failed = false;
+    println!("{:<6}{:<5}{:<20} Part two", "Year", "Day", "Part one");
+    for &day in &selection.days {
+        let input: Arc<str> = match input::read(day.year, day.day, selection.input) {
+            Ok(input) => input.into(),
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let mut answers = Vec::new();
+        for part in Part::BOTH {
+            match solve_part(day, input.clone(), part, timeout) {
+                Ok(answer) => answers.push(answer.to_string()),
+                Err(Failure::Parse(err)) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+                Err(failure) => {
+                    failed = true;
+                    answers.push(failure.to_string());
+                }
+            }
+        }
+        println!(
+            "{:<6}{:<5}{:<20} {}",
+            day.year, day.day, answers[0], answers[1]
+        );
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Solves each part of a single day while recording the frames it draws.
+fn run_recorded(selection: &Selection, output: &Path) -> ExitCode {
+    let [day] = selection.days[..] else {
+        eprintln!("--record needs a single day");
+        return ExitCode::FAILURE;
+    };
+    let input = match input::read(day.year, day.day, selection.input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let output = record::Output::from_path(output);
+    for part in Part::BOTH {
+        let output = output.with_suffix(match part {
+            Part::One => "part1",
+            Part::Two => "part2",
+        });
+        if let Err(err) = record::start(output.clone(), record::Options::default()) {
+            eprintln!("{}: {err}", output.path().display());
+            return ExitCode::FAILURE;
+        }
+        let answer = day.solve_part(&input, part, &Params::default());
+        let summary = match record::finish() {
+            Ok(summary) => summary,
+            Err(err) => {
+                eprintln!("{}: {err}", output.path().display());
+                return ExitCode::FAILURE;
+            }
+        };
+        match answer {
+            Ok(answer) => println!("{}-{:02} {part}: {answer}", day.year, day.day),
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        }
+        match summary {
+            record::Summary { frames: 0, .. } => println!("No frames drawn"),
+            record::Summary { frames, dropped } => {
+                print!("Recorded {frames} frames to {}", output.path().display());
+                if dropped > 0 {
+                    print!(" ({dropped} more dropped)");
+                }
+                println!();
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn bench(selection: &Selection, options: &bench::Options) -> ExitCode {
+    // Panics are reported in the table, so keep their messages off stderr.
+    panic::set_hook(Box::new(|_| {}));
+
+    // A day that cannot be timed gets a row saying why, rather than stopping
+    // the rest.
+    let mut rows = Vec::new();
+    for &day in &selection.days {
+        eprintln!("Timing {}-{:02}", day.year, day.day);
+        let report = match input::read(day.year, day.day, selection.input) {
+            Ok(input) => day
+                .bench(&input, options)
+                .map_err(|err| Failure::Parse(err).to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+        rows.push((day, report));
+    }
+    // The days that never finished first, then the slowest.
+    rows.sort_by_key(|(_, report)| {
+        let total = report.as_ref().ok().and_then(bench::Report::total);
+        (total.is_some(), Reverse(total))
+    });
+
+    let mut failed = false;
+    let mut timed_out = false;
+    println!(
+        "{:<6}{:<5}{:<28}{:<28}{:<28}Total",
+        "Year", "Day", "Parse", "Part one", "Part two"
+    );
+    for (day, report) in rows {
+        match report {
+            Ok(report) => {
+                timed_out |= [report.parse, report.part_one, report.part_two]
+                    .contains(&bench::Timing::TimedOut);
+                println!(
+                    "{:<6}{:<5}{:<28}{:<28}{:<28}{}",
+                    day.year,
+                    day.day,
+                    report.parse,
+                    report.part_one,
+                    report.part_two,
+                    report.total().map_or_else(|| "-".to_string(), bench::human)
+                );
+            }
+            Err(err) => {
+                failed = true;
+                println!("{:<6}{:<5}{err}", day.year, day.day);
+            }
+        }
+    }
+
+    if timed_out {
+        eprintln!("Timed-out phases kept running and may have slowed the days timed after them");
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Why a part has no answer.
+enum Failure {
+    Parse(ParseError),
+    Panic(String),
+    Timeout(Duration),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Parse(err) => write!(f, "parse error: {}", err.message),
+            Failure::Panic(message) => write!(f, "panicked: {message}"),
+            Failure::Timeout(timeout) => write!(f, "timed out after {}s", timeout.as_secs()),
+        }
+    }
+}
+
+/// Solves one part on its own thread, so that panics and runaway solutions
+/// are reported rather than taking the runner down with them. A part that
+/// times out is left running in the background until the runner exits.
+fn solve_part(
+    day: &'static Day,
+    input: Arc<str>,
+    part: Part,
+    timeout: Duration,
+) -> Result<Answer, Failure> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            day.solve_part(&input, part, &Params::default())
+        }));
+        let _ = sender.send(result);
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(Ok(answer))) => Ok(answer),
+        Ok(Ok(Err(err))) => Err(Failure::Parse(err)),
+        Ok(Err(payload)) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(Failure::Panic(message))
+        }
+        Err(_) => Err(Failure::Timeout(timeout)),
+    }
+}
+
+fn verify(selection: &Selection, timeout: Duration) -> ExitCode {
+    // Panics are reported in the table, so keep their messages off stderr.
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut answers = HashMap::new();
+    let (mut ok, mut failed, mut missing) = (0, 0, 0);
+    println!("{:<6}{:<5}{:<39} Part two", "Year", "Day", "Part one");
+    for &day in &selection.days {
+        let recorded = match answers.entry(day.year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => match answers::load(day.year) {
+                Ok(recorded) => entry.insert(recorded),
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            },
+        };
+        let input: Arc<str> = match input::read(day.year, day.day, selection.input) {
+            Ok(input) => input.into(),
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let status = Part::BOTH.map(|part| {
+            let result = solve_part(day, input.clone(), part, timeout);
+            match (recorded.get(day.day, part), result) {
+                (Some(expected), Ok(answer)) if *expected == answer => {
+                    ok += 1;
+                    "ok".to_string()
+                }
+                (Some(expected), Ok(answer)) => {
+                    failed += 1;
+                    format!("wrong: {answer}, expected {expected}")
+                }
+                (Some(_), Err(failure)) => {
+                    failed += 1;
+                    failure.to_string()
+                }
+                (None, Ok(answer)) => {
+                    missing += 1;
+                    format!("missing, got {answer}")
+                }
+                (None, Err(failure)) => {
+                    missing += 1;
+                    format!("missing, {failure}")
+                }
+            }
+        });
+        println!(
+            "{:<6}{:<5}{:<39} {}",
+            day.year, day.day, status[0], status[1]
+        );
+    }
+    println!("\n{ok} ok, {failed} failed, {missing} missing");
+
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn new(year: u16, day: u8) -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    match scaffold::create(root, year, day) {
+        Ok(written) => {
+            for path in written {
+                println!(
+                    "Created {}",
+                    path.strip_prefix(root).unwrap_or(&path).display()
+                );
+            }
+            println!(
+                "Fill in examples/{year}-{day:02}/ and run `cargo test year{year}::day{day:02}`"
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(year: u16, day: u8) -> ExitCode {
+    let client = match Config::load() {
+        Ok(config) => Client::new(config),
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match client.fetch_input(year, day) {
+        Ok((Fetched::Downloaded, path)) => {
+            println!("Downloaded {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Ok((Fetched::AlreadyPresent, path)) => {
+            println!("Already have {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err @ client::Error::Status { status: 404, .. }) => {
+            eprintln!("{err}\n{year}-{day:02} may not be unlocked yet");
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn describe(year: u16, day: u8) -> ExitCode {
+    let client = match Config::load() {
+        Ok(config) => Client::new(config),
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let description = describe::path(year, day);
+    match describe::describe(&client, year, day, &description, &examples::dir(year, day)) {
+        Ok(saved) => {
+            println!("Saved {}", saved.description.display());
+            for path in &saved.examples {
+                println!("Added {}", path.display());
+            }
+            if !saved.examples.is_empty() {
+                println!("Delete any that are not examples, and fill in the answers of the rest");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(day: &Day, part: Part, input: Option<&str>) -> ExitCode {
+    let client = match Config::load() {
+        Ok(config) => Client::new(config),
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut history = match History::load(submit::path(day.year)) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = match input::read(day.year, day.day, input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let answer = match day.solve_part(&input, part, &Params::default()) {
+        Ok(answer) => answer,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{}-{:02} {part}: {answer}", day.year, day.day);
+    match submit::submit(&client, &mut history, day.year, day.day, part, &answer) {
+        Ok(Outcome::Correct) => {
+            println!(
+                "Correct! Record it in answers/{}.toml for `aoc verify`",
+                day.year
+            );
+            ExitCode::SUCCESS
+        }
+        Ok(outcome) => {
+            println!("{}", capitalize(&outcome.to_string()));
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn picture(day: &Day, part: Part, output: &Path, input: Option<&str>) -> ExitCode {
+    let input = match input::read(day.year, day.day, input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let picture = match day.picture(&input, part) {
+        Ok(Some(picture)) => picture,
+        Ok(None) => {
+            eprintln!("{}-{:02} has no picture of {part}", day.year, day.day);
+            return ExitCode::FAILURE;
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let output = match output.extension() {
+        Some(_) => output.to_path_buf(),
+        None => output.with_extension(picture.extension()),
+    };
+    match picture.save(&output) {
+        Ok(()) => {
+            println!("Saved {}", output.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}: {err}", output.display());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map_or_else(String::new, |c| c.to_uppercase().chain(chars).collect())
+}
+
+/// The `<year> <day> <part> [<input>]` arguments of `aoc submit`.
+fn submission(args: &[String]) -> Option<(&'static Day, Part, Option<&str>)> {
+    let [year, day, part, input @ ..] = args else {
+        return None;
+    };
+    if input.len() > 1 {
+        return None;
+    }
+    let (year, day): (u16, u8) = (year.parse().ok()?, day.parse().ok()?);
+    let day = aoc::days().find(|d| (d.year, d.day) == (year, day))?;
+    Some((day, part_arg(part)?, input.first().map(String::as_str)))
+}
+
+/// The `<year> <day> <part> <path> [<input>]` arguments of `aoc picture`.
+fn picture_args(args: &[String]) -> Option<(&'static Day, Part, PathBuf, Option<&str>)> {
+    let [year, day, part, output, input @ ..] = args else {
+        return None;
+    };
+    if input.len() > 1 {
+        return None;
+    }
+    let (year, day): (u16, u8) = (year.parse().ok()?, day.parse().ok()?);
+    let day = aoc::days().find(|d| (d.year, d.day) == (year, day))?;
+    Some((
+        day,
+        part_arg(part)?,
+        PathBuf::from(output),
+        input.first().map(String::as_str),
+    ))
+}
+
+/// A `<part>` argument, `1` or `2`.
+fn part_arg(arg: &str) -> Option<Part> {
+    match arg {
+        "1" => Some(Part::One),
+        "2" => Some(Part::Two),
+        _ => None,
+    }
+}
+
+/// The `<year> <day>` arguments of `aoc new` and `aoc fetch`, for a day that
+/// may not have a solution yet.
+fn puzzle(args: &[String]) -> Option<(u16, u8)> {
+    match args {
+        [year, day] => {
+            let day = day.parse().ok().filter(|day| (1..=25).contains(day))?;
+            Some((year.parse().ok()?, day))
+        }
+        _ => None,
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("{USAGE}");
+    ExitCode::from(2)
+}
+
+/// Runs `f` on the days selected by the positional arguments.
+fn with_selection(args: &[String], f: impl FnOnce(&Selection) -> ExitCode) -> ExitCode {
+    match select(args) {
+        Some(selection) if !selection.days.is_empty() => f(&selection),
+        Some(_) => {
+            eprintln!("No solutions registered for {}", args.join(" "));
+            ExitCode::FAILURE
+        }
+        None => usage(),
+    }
+}
+
+/// Removes the `-v`, `-vv` and `--verbose` flags from the arguments,
+/// returning how verbose they asked for logging to be.
+fn verbosity(args: &mut Vec<String>) -> u8 {
+    let mut verbosity = 0;
+    args.retain(|arg| {
+        let count = match arg.as_str() {
+            "-v" | "--verbose" => 1,
+            "-vv" => 2,
+            _ => return true,
+        };
+        verbosity += count;
+        false
+    });
+    verbosity
+}
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    logging::init(verbosity(&mut args));
+    let Some((command, positional)) = command(&args) else {
+        return usage();
+    };
+    match command {
+        Command::Run {
+            record: None,
+            timeout,
+        } => with_selection(&positional, |s| run(s, timeout)),
+        Command::Run {
+            record: Some(output),
+            ..
+        } => with_selection(&positional, |s| run_recorded(s, &output)),
+        Command::Bench(options) => with_selection(&positional, |s| bench(s, &options)),
+        Command::Verify { timeout } => with_selection(&positional, |s| verify(s, timeout)),
+        Command::New => puzzle(&positional).map_or_else(usage, |(year, day)| new(year, day)),
+        Command::Fetch => puzzle(&positional).map_or_else(usage, |(year, day)| fetch(year, day)),
+        Command::Describe => {
+            puzzle(&positional).map_or_else(usage, |(year, day)| describe(year, day))
+        }
+        Command::Submit => match submission(&positional) {
+            Some((day, part, input)) => submit(day, part, input),
+            None => usage(),
+        },
+        Command::Picture => match picture_args(&positional) {
+            Some((day, part, output, input)) => picture(day, part, &output, input),
+            None => usage(),
+        },
+    }
+}
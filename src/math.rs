@@ -0,0 +1,251 @@
+//! Number theory: greatest common divisors and least common multiples,
+//! modular arithmetic, and the Chinese Remainder Theorem.
+//!
+//! [`gcd`] and [`lcm`] work on any primitive integer type. The modular
+//! functions work on `u64` and `i64`, widening to 128 bits where a product
+//! could overflow.
+
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// The primitive integer types.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+/// The signed primitive integer types.
+pub trait Signed: Integer + Neg<Output = Self> {
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty),*; $($signed:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                self
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        })*
+
+        $(impl Integer for $signed {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                <$signed>::abs(self)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$signed>::checked_mul(self, rhs)
+            }
+        }
+
+        impl Signed for $signed {
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$signed>::rem_euclid(self, rhs)
+            }
+        })*
+    };
+}
+
+integer!(u8, u16, u32, u64, u128, usize; i8, i16, i32, i64, i128, isize);
+
+/// The greatest common divisor of `a` and `b`, which is never negative.
+/// `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+/// The least common multiple of `a` and `b`, or `None` if it does not fit in
+/// `T`. It is never negative, and is 0 if either is 0.
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // Dividing first means only an lcm too big for `T` can overflow.
+    (a / gcd(a, b)).checked_mul(b).map(T::abs)
+}
+
+/// The least common multiple of `a` and `b`; see [`checked_lcm`].
+///
+/// # Panics
+///
+/// If the least common multiple does not fit in `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm({a:?}, {b:?}) overflows"))
+}
+
+/// The least common multiple of all of `numbers`, which is 1 if there are
+/// none.
+///
+/// # Panics
+///
+/// If the least common multiple does not fit in `T`.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// The greatest common divisor `g` of `a` and `b` with coefficients `x` and
+/// `y` such that `a * x + b * y == g`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::ONE, T::ZERO);
+    let (mut y0, mut y1) = (T::ZERO, T::ONE);
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < T::ZERO {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x` congruent to 1, if `a` and `modulus`
+/// are coprime.
+pub fn mod_inverse<T: Signed>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/// `a * b % modulus`, without overflowing.
+pub fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// `base` to the power `exp`, modulo `modulus`, by repeated squaring.
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` given as
+/// `(residue, modulus)` pairs, whose moduli need not be coprime. The solution
+/// is returned in the same form, as the `x` in `0..m` and the least common
+/// multiple `m` of the moduli; an empty system gives `(0, 1)`.
+///
+/// Returns `None` if the congruences contradict each other, or if `m` does
+/// not fit in an `i64`.
+///
+/// # Panics
+///
+/// If a modulus is not positive.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0_i128, 1_i128);
+    for (residue, modulus) in congruences {
+        assert!(modulus > 0, "modulus {modulus} is not positive");
+        let (residue, modulus) = (residue as i128, modulus as i128);
+        // x + m * k ≡ residue (mod modulus), so m * k ≡ residue - x, which
+        // has a solution when gcd(m, modulus) divides the difference.
+        let (g, inverse, _) = extended_gcd(m, modulus);
+        let difference = residue - x;
+        if difference % g != 0 {
+            return None;
+        }
+        let step = modulus / g;
+        let k = (difference / g % step * inverse).rem_euclid(step);
+        x += m * k;
+        m *= step;
+        if m > i64::MAX as i128 {
+            return None;
+        }
+        x = x.rem_euclid(m);
+    }
+    Some((x as i64, m as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12_u32, 18), 6);
+        assert_eq!(gcd(-12_i64, 18), 6);
+        assert_eq!(gcd(12_i64, -18), 6);
+        assert_eq!(gcd(0_u8, 7), 7);
+        assert_eq!(gcd(0_i32, 0), 0);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4_u8, 6), 12);
+        assert_eq!(lcm(-4_i32, 6), 12);
+        assert_eq!(lcm(0_u64, 6), 0);
+        // The product overflows a u8, but the lcm does not.
+        assert_eq!(lcm(100_u8, 200), 200);
+        assert_eq!(checked_lcm(100_u8, 3), None);
+        assert_eq!(lcm_all([2, 3, 4]), 12);
+        assert_eq!(lcm_all(1_usize..10), 2520);
+        assert_eq!(lcm_all(Vec::<u64>::new()), 1);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240_i64, 46), (-240, 46), (46, -240), (7, 0), (0, -7)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b), "gcd of {a} and {b}");
+            assert_eq!(a * x + b * y, g, "coefficients of {a} and {b}");
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3_i64, 11), Some(4));
+        assert_eq!(mod_inverse(-3_i64, 11), Some(7));
+        assert_eq!(mod_inverse(6_i32, 9), None);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(2, 0, 1), 0);
+        assert_eq!(mod_pow(u64::MAX, u64::MAX, 1_000_000_007), 254_368_884);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli sharing factors.
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(0, i64::MAX), (0, 2)]), None);
+    }
+}
@@ -0,0 +1,211 @@
+//! Parse errors that point at the offending input, and small helpers for
+//! producing them.
+//!
+//! Parsers work on slices of the puzzle input, so an error is created from the
+//! fragment being parsed and only later [located](ParseError::locate) within
+//! the whole input, which turns the fragment into a line and column. That way
+//! helper functions deep inside a parser don't need the full input threaded
+//! through them.
+
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The puzzle being parsed, as `(year, day)`.
+    pub day: Option<(u16, u8)>,
+    pub location: Option<Location>,
+    /// The fragment of input the error is about.
+    pub text: String,
+    pub message: String,
+    address: usize,
+}
+
+/// Where a [`ParseError`] occurred. Lines and columns are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    /// The full line containing the error.
+    pub source: String,
+}
+
+impl ParseError {
+    /// An error about `fragment`, which should be a slice of the input.
+    pub fn new(fragment: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            location: None,
+            text: fragment.to_string(),
+            message: message.into(),
+            address: fragment.as_ptr() as usize,
+        }
+    }
+
+    /// An error about something missing just after `fragment`.
+    pub fn end_of(fragment: &str, message: impl Into<String>) -> Self {
+        ParseError::new(&fragment[fragment.len()..], message)
+    }
+
+    pub fn for_day(self, year: u16, day: u8) -> Self {
+        ParseError {
+            day: Some((year, day)),
+            ..self
+        }
+    }
+
+    /// Works out the line and column of the error within `input`, the string
+    /// the parser was given. Errors about text that did not come from `input`
+    /// are left unlocated.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let Some(offset) = self.address.checked_sub(start) else {
+            return self;
+        };
+        if offset > input.len() || !input.is_char_boundary(offset) {
+            return self;
+        }
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        self.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            source: input[line_start..line_end].to_string(),
+        });
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "parse error")?;
+        if let Some((year, day)) = self.day {
+            write!(f, " in {year}-{day:02}")?;
+        }
+        if let Some(location) = &self.location {
+            write!(f, " at line {}, column {}", location.line, location.column)?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(Location {
+            line,
+            column,
+            source,
+        }) = &self.location
+        {
+            let gutter = line.to_string().len();
+            let underline = "^".repeat(self.text.chars().count().max(1));
+            write!(f, "\n{:gutter$} |", "")?;
+            write!(f, "\n{line} | {source}")?;
+            write!(
+                f,
+                "\n{:gutter$} | {:>width$}",
+                "",
+                underline,
+                width = column - 1 + underline.len()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `s` as a number (or anything else implementing [`FromStr`]).
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError::new(s, format!("expected a number, found {s:?}")))
+}
+
+pub fn split_once<'a>(s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::end_of(s, format!("expected {delimiter:?}")))
+}
+
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(&s[..0], format!("expected {prefix:?}")))
+}
+
+pub fn strip_suffix<'a>(s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+    s.strip_suffix(suffix)
+        .ok_or_else(|| ParseError::end_of(s, format!("expected {suffix:?}")))
+}
+
+/// The one occurrence of `c` in `s`, such as the start of a map.
+pub fn find_one(s: &str, c: char) -> Result<&str, ParseError> {
+    let mut found = s.match_indices(c).map(|(_, found)| found);
+    let first = found
+        .next()
+        .ok_or_else(|| ParseError::end_of(s, format!("expected a {c:?}")))?;
+    match found.next() {
+        Some(second) => Err(ParseError::new(second, format!("expected only one {c:?}"))),
+        None => Ok(first),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const INPUT: &str = indoc! {"
+        seeds: 79 14
+        50 9x 2
+    "};
+
+    #[test]
+    fn test_locate() {
+        let fragment = &INPUT[16..18];
+        assert_eq!(fragment, "9x");
+        let error = number::<u64>(fragment).unwrap_err().locate(INPUT);
+        assert_eq!(
+            error.location,
+            Some(Location {
+                line: 2,
+                column: 4,
+                source: "50 9x 2".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_locate_foreign_text() {
+        let foreign = String::from("9x");
+        let error = number::<u64>(&foreign).unwrap_err().locate(INPUT);
+        assert_eq!(error.location, None);
+    }
+
+    #[test]
+    fn test_display() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let error = split_once(line, ": ")
+            .unwrap_err()
+            .locate(INPUT)
+            .for_day(2023, 5);
+        assert_eq!(
+            error.to_string(),
+            indoc! {r#"
+                parse error in 2023-05 at line 2, column 8: expected ": "
+                  |
+                2 | 50 9x 2
+                  |        ^"#}
+        );
+    }
+
+    #[test]
+    fn test_find_one() {
+        let map = "..S\n.#.\n";
+        assert_eq!(find_one(map, 'S').unwrap().as_ptr(), map[2..].as_ptr());
+        assert_eq!(
+            find_one("S.S", 'S')
+                .unwrap_err()
+                .locate("S.S")
+                .location
+                .unwrap()
+                .column,
+            3
+        );
+        assert!(find_one("...", 'S').is_err());
+    }
+}
@@ -0,0 +1,244 @@
+//! Recording the steps of a simulation as frames, for `aoc run --record`.
+//!
+//! Solvers call [`frame`] at each step with a closure that draws the current
+//! state. Until a recording is [started](start), that is a single relaxed
+//! atomic load and the closure is never called, so solvers can leave their
+//! frames in at no measurable cost.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use crate::image::{Animation, Raster};
+
+/// Where a recording goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Output {
+    /// An animated PNG.
+    Animation(PathBuf),
+    /// A directory of numbered PNGs, `0001.png` onwards.
+    Frames(PathBuf),
+}
+
+impl Output {
+    /// An animation for a path ending in `.png`, and a directory of frames
+    /// for any other.
+    pub fn from_path(path: &Path) -> Self {
+        if path.extension().is_some_and(|ext| ext == "png") {
+            Output::Animation(path.to_path_buf())
+        } else {
+            Output::Frames(path.to_path_buf())
+        }
+    }
+
+    /// The output for one of several recordings made together, told apart by
+    /// `suffix`: `tilt.png` becomes `tilt-<suffix>.png`, and `frames`
+    /// becomes `frames/<suffix>`.
+    pub fn with_suffix(&self, suffix: &str) -> Self {
+        match self {
+            Output::Animation(path) => {
+                let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                Output::Animation(path.with_file_name(format!("{stem}-{suffix}.png")))
+            }
+            Output::Frames(dir) => Output::Frames(dir.join(suffix)),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        match self {
+            Output::Animation(path) | Output::Frames(path) => path,
+        }
+    }
+}
+
+/// How to record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Frames beyond this many are dropped, so that a long simulation cannot
+    /// fill the disk.
+    pub limit: usize,
+    /// How long each frame of an animation is shown.
+    pub delay: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            limit: 1000,
+            delay: Duration::from_millis(100),
+        }
+    }
+}
+
+/// What a finished recording wrote.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub frames: usize,
+    /// Frames past the limit, which were not recorded.
+    pub dropped: usize,
+}
+
+struct Recording {
+    output: Output,
+    options: Options,
+    animation: Animation,
+    frames: usize,
+    dropped: usize,
+    error: Option<io::Error>,
+}
+
+impl Recording {
+    fn push(&mut self, frame: &Raster) -> io::Result<()> {
+        match &self.output {
+            Output::Animation(_) => self.animation.push(frame),
+            Output::Frames(dir) => {
+                let path = dir.join(format!("{:04}.png", self.frames + 1));
+                frame.write_png(io::BufWriter::new(fs::File::create(path)?))
+            }
+        }
+    }
+}
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static CURRENT: Mutex<Option<Recording>> = Mutex::new(None);
+
+/// Whether a recording is under way, for solvers whose frames need more
+/// than a closure to set up.
+#[inline]
+pub fn enabled() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+/// Records the frame `draw` draws, if a recording is under way.
+#[inline]
+pub fn frame(draw: impl FnOnce() -> Raster) {
+    if enabled() {
+        push(draw);
+    }
+}
+
+/// Draws and records a frame, unless the recording has failed or is full.
+fn push(draw: impl FnOnce() -> Raster) {
+    let mut current = CURRENT.lock().unwrap();
+    let Some(recording) = current.as_mut() else {
+        return;
+    };
+    if recording.error.is_some() {
+        return;
+    }
+    if recording.frames >= recording.options.limit {
+        recording.dropped += 1;
+        return;
+    }
+    match recording.push(&draw()) {
+        Ok(()) => recording.frames += 1,
+        Err(err) => recording.error = Some(err),
+    }
+}
+
+/// Starts recording the frames that solvers produce to `output`, replacing
+/// any recording already under way.
+pub fn start(output: Output, options: Options) -> io::Result<()> {
+    match &output {
+        Output::Frames(dir) => fs::create_dir_all(dir)?,
+        Output::Animation(path) => {
+            if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                fs::create_dir_all(dir)?;
+            }
+        }
+    }
+    *CURRENT.lock().unwrap() = Some(Recording {
+        animation: Animation::new(options.delay),
+        output,
+        options,
+        frames: 0,
+        dropped: 0,
+        error: None,
+    });
+    RECORDING.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Stops recording, and writes out the animation if there is one. Nothing
+/// is written if no frames were recorded.
+pub fn finish() -> io::Result<Summary> {
+    RECORDING.store(false, Ordering::Relaxed);
+    let Some(recording) = CURRENT.lock().unwrap().take() else {
+        return Ok(Summary {
+            frames: 0,
+            dropped: 0,
+        });
+    };
+    if let Some(err) = recording.error {
+        return Err(err);
+    }
+    if let Output::Animation(path) = &recording.output {
+        if !recording.animation.is_empty() {
+            let mut out = Vec::new();
+            recording.animation.write_png(&mut out)?;
+            fs::write(path, out)?;
+        }
+    }
+    Ok(Summary {
+        frames: recording.frames,
+        dropped: recording.dropped,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grid::Grid, image::WHITE};
+
+    #[test]
+    fn test_with_suffix() {
+        let animation = Output::from_path(Path::new("out/tilt.png"));
+        assert_eq!(
+            animation.with_suffix("part1"),
+            Output::Animation(PathBuf::from("out/tilt-part1.png"))
+        );
+        let frames = Output::from_path(Path::new("out/tilt"));
+        assert_eq!(
+            frames.with_suffix("part1"),
+            Output::Frames(PathBuf::from("out/tilt/part1"))
+        );
+    }
+
+    // Recording is global, so everything that starts one is in this test.
+    #[test]
+    fn test_record() {
+        let mut drawn = 0;
+        frame(|| {
+            drawn += 1;
+            Raster::from_grid(&Grid::new(1, 1, ()), 1, |_| WHITE)
+        });
+        assert_eq!(drawn, 0);
+
+        let dir = std::env::temp_dir().join(format!("aoc-record-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let options = Options {
+            limit: 2,
+            ..Options::default()
+        };
+        start(Output::Frames(dir.clone()), options).unwrap();
+        for _ in 0..3 {
+            frame(|| Raster::from_grid(&Grid::new(2, 1, ()), 1, |_| WHITE));
+        }
+        assert_eq!(
+            finish().unwrap(),
+            Summary {
+                frames: 2,
+                dropped: 1
+            }
+        );
+        assert!(dir.join("0002.png").exists());
+        assert!(!dir.join("0003.png").exists());
+        assert!(!enabled());
+    }
+}
@@ -0,0 +1,216 @@
+//! Drawing grids in the terminal, to see what a solution is doing.
+//!
+//! A [`Renderer`] draws any [`Grid`] given the character for each cell, with
+//! optional colours per cell and sets of highlighted cells drawn on top.
+//! Renderings are logged, which goes to stderr, so colour is only used when
+//! stderr is a terminal and `NO_COLOR` is unset: logs that are piped or
+//! redirected stay plain text.
+
+use std::{
+    collections::HashSet,
+    env, fmt,
+    io::{self, IsTerminal},
+};
+
+use crate::{grid::Grid, point::Point};
+
+/// One of the standard ANSI terminal colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Colour {
+    /// The SGR code of this colour as a foreground; backgrounds are 10 more.
+    fn foreground(self) -> u8 {
+        match self {
+            Colour::Black => 30,
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::White => 37,
+            Colour::Grey => 90,
+        }
+    }
+}
+
+/// Whether colour should be written to stderr.
+pub fn colour_enabled() -> bool {
+    io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
+/// The Unicode box-drawing character for a pipe drawn in ASCII, as in
+/// 2023-10's sketch of pipes, or the character itself.
+pub fn box_drawing(c: char) -> char {
+    match c {
+        '|' => '│',
+        '-' => '─',
+        'L' => '└',
+        'J' => '┘',
+        '7' => '┐',
+        'F' => '┌',
+        c => c,
+    }
+}
+
+/// The foreground and background colours of a cell.
+type Style = (Option<Colour>, Option<Colour>);
+
+type CellFn<'a, T, U> = Box<dyn Fn(&T) -> U + 'a>;
+
+/// Draws a grid through its [`Display`](fmt::Display) implementation:
+///
+/// ```no_run
+/// # use aoc::{grid::Grid, point::Point, render::{Colour, Renderer}};
+/// let grid = Grid::parse("F-7\n|.|\nL-J\n", Some).unwrap();
+/// let corners = [Point::new(0, 0), Point::new(2, 2)];
+/// log::debug!(
+///     "Corners:\n{}",
+///     Renderer::new(&grid, |&c| c)
+///         .box_drawing(true)
+///         .highlight(corners, Colour::Yellow)
+/// );
+/// ```
+pub struct Renderer<'a, T> {
+    grid: &'a Grid<T>,
+    glyph: CellFn<'a, T, char>,
+    colour: CellFn<'a, T, Option<Colour>>,
+    highlights: Vec<(HashSet<Point>, Colour)>,
+    box_drawing: bool,
+    ansi: bool,
+}
+
+impl<'a, T> Renderer<'a, T> {
+    /// Draws each cell as the character `glyph` gives for it.
+    pub fn new(grid: &'a Grid<T>, glyph: impl Fn(&T) -> char + 'a) -> Self {
+        Renderer {
+            grid,
+            glyph: Box::new(glyph),
+            colour: Box::new(|_| None),
+            highlights: Vec::new(),
+            box_drawing: false,
+            ansi: colour_enabled(),
+        }
+    }
+
+    /// Colours each cell's character as `colour` gives for it.
+    pub fn colour(mut self, colour: impl Fn(&T) -> Option<Colour> + 'a) -> Self {
+        self.colour = Box::new(colour);
+        self
+    }
+
+    /// Gives the background of `cells` the colour `colour`. Later highlights
+    /// are drawn over earlier ones.
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = Point>, colour: Colour) -> Self {
+        self.highlights.push((cells.into_iter().collect(), colour));
+        self
+    }
+
+    /// Draws pipe characters with Unicode box drawing; see [`box_drawing`].
+    pub fn box_drawing(mut self, on: bool) -> Self {
+        self.box_drawing = on;
+        self
+    }
+
+    /// Forces colour on or off, rather than deciding by where stderr goes.
+    pub fn ansi(mut self, on: bool) -> Self {
+        self.ansi = on;
+        self
+    }
+
+    fn style(&self, p: Point, cell: &T) -> Style {
+        let foreground = (self.colour)(cell);
+        let background = self
+            .highlights
+            .iter()
+            .rev()
+            .find(|(cells, _)| cells.contains(&p))
+            .map(|&(_, colour)| colour);
+        (foreground, background)
+    }
+}
+
+fn write_style(f: &mut fmt::Formatter, (foreground, background): Style) -> fmt::Result {
+    write!(f, "\x1b[0")?;
+    if let Some(colour) = foreground {
+        write!(f, ";{}", colour.foreground())?;
+    }
+    if let Some(colour) = background {
+        write!(f, ";{}", colour.foreground() + 10)?;
+    }
+    write!(f, "m")
+}
+
+impl<T> fmt::Display for Renderer<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            let mut current: Style = (None, None);
+            for (x, cell) in row.iter().enumerate() {
+                if self.ansi {
+                    let style = self.style(Point::new(x as i64, y as i64), cell);
+                    if style != current {
+                        write_style(f, style)?;
+                        current = style;
+                    }
+                }
+                let glyph = (self.glyph)(cell);
+                let glyph = if self.box_drawing {
+                    box_drawing(glyph)
+                } else {
+                    glyph
+                };
+                write!(f, "{glyph}")?;
+            }
+            if current != (None, None) {
+                write!(f, "\x1b[0m")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("F7\nLJ\n", Some).unwrap()
+    }
+
+    #[test]
+    fn test_plain() {
+        let grid = example();
+        let renderer = Renderer::new(&grid, |&c| c)
+            .highlight([Point::new(0, 0)], Colour::Red)
+            .ansi(false);
+        assert_eq!(renderer.to_string(), "F7\nLJ\n");
+        assert_eq!(renderer.box_drawing(true).to_string(), "┌┐\n└┘\n");
+    }
+
+    #[test]
+    fn test_ansi() {
+        let grid = example();
+        let renderer = Renderer::new(&grid, |&c| c)
+            .colour(|&c| (c == 'J').then_some(Colour::Blue))
+            .highlight([Point::new(0, 0), Point::new(1, 1)], Colour::Red)
+            .highlight([Point::new(0, 0)], Colour::Green)
+            .ansi(true);
+        assert_eq!(
+            renderer.to_string(),
+            "\x1b[0;42mF\x1b[0m7\n\
+             L\x1b[0;34;41mJ\x1b[0m\n"
+        );
+    }
+}
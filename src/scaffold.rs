@@ -0,0 +1,259 @@
+//! Scaffolding for a new puzzle, as `aoc new <year> <day>` creates it: a day
+//! module implementing [`Solution`](crate::Solution) with a pending test, an
+//! empty input, an empty example fixture, and the registration that makes
+//! the runner aware of the day.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::{examples, input};
+
+/// The source of a new day module, whose parts panic until they are written.
+pub fn day_module(year: u16, day: u8) -> String {
+    format!(
+        r#"use crate::{{parse::ParseError, Answer, Solution}};
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {{
+    Ok(input.lines().map(|line| line.to_string()).collect())
+}}
+
+fn part_one(_lines: &[String]) -> u64 {{
+    todo!("{year}-{day:02} part one")
+}}
+
+fn part_two(_lines: &[String]) -> u64 {{
+    todo!("{year}-{day:02} part two")
+}}
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        parse(input)
+    }}
+
+    fn part_one(lines: &Self::Input) -> Answer {{
+        part_one(lines).into()
+    }}
+
+    fn part_two(lines: &Self::Input) -> Answer {{
+        part_two(lines).into()
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../examples/{year}-{day:02}/01.txt");
+
+    #[test]
+    #[ignore = "pending: fill in examples/{year}-{day:02}/01.txt and 01.toml"]
+    fn test_parse() {{
+        assert!(!parse(EXAMPLE).unwrap().is_empty());
+    }}
+}}
+"#
+    )
+}
+
+/// Adds `pub mod dayNN;` and the day's registry entry to a year's `mod.rs`,
+/// keeping both in day order.
+pub fn register_day(mod_rs: &str, year: u16, day: u8) -> String {
+    let header: Vec<&str> = mod_rs
+        .lines()
+        .take_while(|line| !line.starts_with("pub mod") && !line.starts_with("use "))
+        .collect();
+
+    let mut modules: Vec<String> = mod_rs
+        .lines()
+        .filter(|line| line.starts_with("pub mod day"))
+        .map(str::to_string)
+        .collect();
+    modules.push(format!("pub mod day{day:02};"));
+    modules.sort();
+    modules.dedup();
+
+    let days = mod_rs
+        .split_once("= &[")
+        .and_then(|(_, rest)| rest.split_once("];"))
+        .map_or("", |(days, _)| days);
+    let mut entries: Vec<String> = days
+        .split("),")
+        .map(|entry| entry.trim().trim_end_matches(')'))
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| format!("{entry})"))
+        .collect();
+    entries.push(format!(
+        "Day::new::<day{day:02}::Day{day:02}>({year}, {day})"
+    ));
+    entries.sort();
+    entries.dedup();
+
+    let days = match &entries[..] {
+        [entry] => format!("&[{entry}]"),
+        _ => format!("&[\n    {},\n]", entries.join(",\n    ")),
+    };
+    format!(
+        "{}\n{}\n\nuse crate::Day;\n\npub const DAYS: &[Day] = {days};\n",
+        header.join("\n"),
+        modules.join("\n"),
+    )
+}
+
+/// The `mod.rs` for a year that has no solutions yet.
+fn year_module(year: u16) -> String {
+    format!(
+        "//! Solutions for [Advent of Code {year}](https://adventofcode.com/{year}).\n\n\
+         use crate::Day;\n\npub const DAYS: &[Day] = &[];\n"
+    )
+}
+
+/// Declares a new year's module in `lib.rs` and chains its days onto
+/// [`days`](crate::days).
+pub fn register_year(lib_rs: &str, year: u16) -> String {
+    let mut lines: Vec<String> = lib_rs.lines().map(str::to_string).collect();
+    let last_module = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod year"))
+        .expect("lib.rs should declare the year modules");
+    lines.insert(last_module + 1, format!("pub mod year{year};"));
+    for line in &mut lines {
+        if line.trim_start().starts_with("year") && line.contains("::DAYS.iter()") {
+            *line = format!("{}.chain(year{year}::DAYS)", line.trim_end());
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Creates everything a new day needs under `root`, the repository, and
+/// returns the paths it wrote. Existing inputs and examples are kept, but
+/// an existing day module is an error.
+pub fn create(root: &Path, year: u16, day: u8) -> io::Result<Vec<PathBuf>> {
+    let year_dir = root.join("src").join(format!("year{year}"));
+    let module = year_dir.join(format!("day{day:02}.rs"));
+    if module.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module.display()),
+        ));
+    }
+
+    let mut written = Vec::new();
+    let mut write = |path: PathBuf, contents: &str, overwrite: bool| -> io::Result<()> {
+        if overwrite || !path.exists() {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&path, contents)?;
+            written.push(path);
+        }
+        Ok(())
+    };
+
+    let mod_rs = year_dir.join("mod.rs");
+    let year_exists = mod_rs.exists();
+    if !year_exists {
+        let lib_rs = root.join("src/lib.rs");
+        write(
+            lib_rs.clone(),
+            &register_year(&fs::read_to_string(&lib_rs)?, year),
+            true,
+        )?;
+    }
+    write(module, &day_module(year, day), true)?;
+    let mod_contents = if year_exists {
+        fs::read_to_string(&mod_rs)?
+    } else {
+        year_module(year)
+    };
+    write(mod_rs, &register_day(&mod_contents, year, day), true)?;
+
+    let relative =
+        |path: PathBuf| root.join(path.strip_prefix(env!("CARGO_MANIFEST_DIR")).unwrap());
+    write(relative(input::default_path(year, day)), "", false)?;
+    let examples = relative(examples::dir(year, day));
+    write(examples.join("01.txt"), "", false)?;
+    write(examples.join("01.toml"), examples::ANSWERS_TEMPLATE, false)?;
+
+    // Tidy up the generated and edited code if rustfmt is around; it is
+    // valid either way.
+    let sources = written
+        .iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"));
+    let _ = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args(sources)
+        .status();
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_register_day() {
+        let mod_rs = indoc! {"
+            //! Solutions for [Advent of Code 2022](https://adventofcode.com/2022).
+
+            pub mod day01;
+
+            use crate::Day;
+
+            pub const DAYS: &[Day] = &[Day::new::<day01::Day01>(2022, 1)];
+        "};
+        assert_eq!(
+            register_day(mod_rs, 2022, 2),
+            indoc! {"
+                //! Solutions for [Advent of Code 2022](https://adventofcode.com/2022).
+
+                pub mod day01;
+                pub mod day02;
+
+                use crate::Day;
+
+                pub const DAYS: &[Day] = &[
+                    Day::new::<day01::Day01>(2022, 1),
+                    Day::new::<day02::Day02>(2022, 2),
+                ];
+            "}
+        );
+    }
+
+    #[test]
+    fn test_register_day_in_new_year() {
+        let registered = register_day(&year_module(2024), 2024, 1);
+        assert!(registered.contains("pub mod day01;\n"));
+        assert!(registered.ends_with("&[Day::new::<day01::Day01>(2024, 1)];\n"));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let lib_rs = indoc! {"
+            pub mod year2022;
+            pub mod year2023;
+
+            pub fn days() -> impl Iterator<Item = &'static Day> {
+                year2022::DAYS.iter().chain(year2023::DAYS)
+            }
+        "};
+        assert_eq!(
+            register_year(lib_rs, 2024),
+            indoc! {"
+                pub mod year2022;
+                pub mod year2023;
+                pub mod year2024;
+
+                pub fn days() -> impl Iterator<Item = &'static Day> {
+                    year2022::DAYS.iter().chain(year2023::DAYS).chain(year2024::DAYS)
+                }
+            "}
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::{image::Picture, parse::ParseError};
+
+/// The answer to one part of a puzzle.
+///
+/// Almost every puzzle asks for a number, but a few ask for a word or a code,
+/// so both are representable. Numbers are stored widely enough to hold any of
+/// the integer types the solutions compute with.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => n.fmt(f),
+            Answer::Text(s) => s.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// One of the two halves of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part one"),
+            Part::Two => write!(f, "part two"),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// Named puzzle parameters, such as a number of steps, for puzzles whose
+/// examples use different values from the real input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, i64>);
+
+impl Params {
+    pub fn get(&self, name: &str) -> Option<i64> {
+        self.0.get(name).copied()
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, value: i64) {
+        self.0.insert(name.into(), value);
+    }
+}
+
+/// A puzzle solution: how to read the input, and how to answer each part.
+///
+/// The input is parsed once and shared by both parts, so parts that need to
+/// mutate it (simulations, for instance) work on their own copy. Malformed
+/// input is reported as a [`ParseError`] rather than a panic.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Answer;
+    fn part_two(input: &Self::Input) -> Answer;
+
+    /// Answers part one with the given [`Params`]. Only puzzles that have
+    /// parameters need to override this, falling back to the real input's
+    /// values for any that are missing.
+    fn part_one_with(input: &Self::Input, _params: &Params) -> Answer {
+        Self::part_one(input)
+    }
+
+    /// Answers part two with the given [`Params`], like
+    /// [`part_one_with`](Solution::part_one_with).
+    fn part_two_with(input: &Self::Input, _params: &Params) -> Answer {
+        Self::part_two(input)
+    }
+
+    /// Pictures a part's solution for a write-up, as `aoc picture` saves it.
+    /// Most puzzles have nothing worth drawing and keep the default of `None`.
+    fn picture(_input: &Self::Input, _part: Part) -> Option<Picture> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42_u64).to_string(), "42");
+        assert_eq!(Answer::from(-7_i64).to_string(), "-7");
+        assert_eq!(Answer::from("RFSLJDHNT").to_string(), "RFSLJDHNT");
+    }
+
+    #[test]
+    fn test_integer_types_compare_equal() {
+        assert_eq!(Answer::from(405_usize), Answer::from(405_u32));
+    }
+}
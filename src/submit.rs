@@ -0,0 +1,596 @@
+//! Submitting answers, as `aoc submit <year> <day> <part>` does.
+//!
+//! Every attempt is recorded in `submissions/<year>.toml`, so that an answer
+//! the site has already rejected is refused before it is sent again:
+//!
+//! ```toml
+//! [[attempt]]
+//! day = 5
+//! part = 2
+//! answer = 1240035
+//! outcome = "too high"
+//! time = 1701763200
+//! ```
+//!
+//! Besides exact repeats, this refuses numbers at or above a known "too
+//! high" (or at or below a known "too low"), a part that has already been
+//! answered correctly, and anything sent while the site has asked us to wait.
+
+use std::{
+    fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    client::{self, Client},
+    Answer, Part,
+};
+
+/// What the site made of a submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint as to which way.
+    Wrong,
+    /// Sent too soon after the last attempt, so the answer was not checked.
+    Wait(Duration),
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Recognises the outcome in the page returned for a submission.
+    fn recognise(page: &str) -> Option<Outcome> {
+        if page.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if page.contains("That's not the right answer") {
+            Some(if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            })
+        } else if page.contains("You gave an answer too recently") {
+            parse_wait(page).map(Outcome::Wait)
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Outcome::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    /// How the outcome is written in the history.
+    fn name(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wrong => "wrong",
+            Outcome::Wait(_) => "wait",
+            Outcome::AlreadySolved => "already solved",
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Wait(wait) => write!(f, "wait {}s before trying again", wait.as_secs()),
+            outcome => f.write_str(outcome.name()),
+        }
+    }
+}
+
+/// Reads the wait out of e.g. "You have 1m 5s left to wait."
+fn parse_wait(page: &str) -> Option<Duration> {
+    let (_, rest) = page.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    let mut seconds = 0;
+    for term in wait.split_whitespace() {
+        let unit = match term.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        seconds += unit * term[..term.len() - 1].parse::<u64>().ok()?;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// One submission and what came of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub outcome: Outcome,
+    /// When it was sent, in seconds since the Unix epoch.
+    pub time: u64,
+}
+
+impl Attempt {
+    fn to_toml(&self) -> String {
+        let answer = match &self.answer {
+            Answer::Number(n) => match i64::try_from(*n) {
+                Ok(n) => toml::Value::from(n),
+                Err(_) => toml::Value::from(n.to_string()),
+            },
+            Answer::Text(s) => toml::Value::from(s.as_str()),
+        };
+        let mut toml = format!(
+            "[[attempt]]\nday = {}\npart = {}\nanswer = {answer}\noutcome = {}\ntime = {}\n",
+            self.day,
+            self.part as u8 + 1,
+            toml::Value::from(self.outcome.name()),
+            self.time,
+        );
+        if let Outcome::Wait(wait) = self.outcome {
+            toml.push_str(&format!("wait = {}\n", wait.as_secs()));
+        }
+        toml
+    }
+
+    fn from_toml(table: &toml::Table) -> Result<Attempt, String> {
+        let integer = |key: &str| {
+            table
+                .get(key)
+                .and_then(toml::Value::as_integer)
+                .ok_or_else(|| format!("{key} should be an integer"))
+        };
+        let part = match integer("part")? {
+            1 => Part::One,
+            2 => Part::Two,
+            part => return Err(format!("part {part} should be 1 or 2")),
+        };
+        // Numbers too big for TOML are written as strings.
+        let answer = match table.get("answer") {
+            Some(toml::Value::Integer(n)) => Answer::from(*n),
+            Some(toml::Value::String(s)) => s
+                .parse::<i128>()
+                .map_or_else(|_| Answer::from(s.as_str()), Answer::Number),
+            _ => return Err("answer should be an integer or string".to_string()),
+        };
+        let outcome = match table.get("outcome").and_then(toml::Value::as_str) {
+            Some("correct") => Outcome::Correct,
+            Some("too high") => Outcome::TooHigh,
+            Some("too low") => Outcome::TooLow,
+            Some("wrong") => Outcome::Wrong,
+            Some("wait") => Outcome::Wait(Duration::from_secs(integer("wait")? as u64)),
+            Some("already solved") => Outcome::AlreadySolved,
+            outcome => return Err(format!("unknown outcome {outcome:?}")),
+        };
+        Ok(Attempt {
+            day: integer("day")? as u8,
+            part,
+            answer,
+            outcome,
+            time: integer("time")? as u64,
+        })
+    }
+}
+
+/// Why an answer was not sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect(Answer),
+    KnownWrong(Outcome),
+    NotBelowTooHigh(Answer),
+    NotAboveTooLow(Answer),
+    Wait(Duration),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => write!(f, "already answered correctly: {answer}"),
+            Refusal::KnownWrong(outcome) => write!(f, "already submitted: {outcome}"),
+            Refusal::NotBelowTooHigh(answer) => write!(f, "{answer} was already too high"),
+            Refusal::NotAboveTooLow(answer) => write!(f, "{answer} was already too low"),
+            Refusal::Wait(wait) => write!(f, "wait {}s before trying again", wait.as_secs()),
+        }
+    }
+}
+
+/// A submission history that could not be read, parsed or written.
+#[derive(Debug)]
+pub struct HistoryError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "submission history {}: {}",
+            self.path.display(),
+            self.message
+        )
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+/// The attempts made for one year, and the file they are kept in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+/// Where the attempts for a year are recorded, independent of the working
+/// directory.
+pub fn path(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("submissions")
+        .join(format!("{year}.toml"))
+}
+
+impl History {
+    /// Loads the history kept at `path`, which may not exist yet.
+    pub fn load(path: PathBuf) -> Result<History, HistoryError> {
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => {
+                return Err(HistoryError {
+                    path,
+                    message: err.to_string(),
+                })
+            }
+        };
+        match parse_attempts(&text) {
+            Ok(attempts) => Ok(History { path, attempts }),
+            Err(message) => Err(HistoryError { path, message }),
+        }
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Decides whether `answer` is worth sending at `now`, in seconds since
+    /// the Unix epoch.
+    pub fn check(&self, day: u8, part: Part, answer: &Answer, now: u64) -> Result<(), Refusal> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|attempt| (attempt.day, attempt.part) == (day, part));
+        for attempt in attempts {
+            match (attempt.outcome, &attempt.answer, answer) {
+                (Outcome::Correct, correct, _) => {
+                    return Err(Refusal::AlreadyCorrect(correct.clone()))
+                }
+                (outcome, previous, _) if outcome.is_wrong() && previous == answer => {
+                    return Err(Refusal::KnownWrong(outcome))
+                }
+                (Outcome::TooHigh, Answer::Number(high), Answer::Number(n)) if n >= high => {
+                    return Err(Refusal::NotBelowTooHigh(attempt.answer.clone()))
+                }
+                (Outcome::TooLow, Answer::Number(low), Answer::Number(n)) if n <= low => {
+                    return Err(Refusal::NotAboveTooLow(attempt.answer.clone()))
+                }
+                (Outcome::Wait(wait), _, _) if now < attempt.time + wait.as_secs() => {
+                    return Err(Refusal::Wait(Duration::from_secs(
+                        attempt.time + wait.as_secs() - now,
+                    )))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends an attempt to the history file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), HistoryError> {
+        let error = |err: io::Error| HistoryError {
+            path: self.path.clone(),
+            message: err.to_string(),
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(error)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(error)?;
+        let separator = if self.attempts.is_empty() { "" } else { "\n" };
+        write!(file, "{separator}{}", attempt.to_toml()).map_err(error)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+fn parse_attempts(text: &str) -> Result<Vec<Attempt>, String> {
+    let mut table: toml::Table = text
+        .parse()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+    let attempts = match table.remove("attempt") {
+        Some(toml::Value::Array(attempts)) => attempts,
+        Some(_) => return Err("attempt should be an array of tables".to_string()),
+        None => Vec::new(),
+    };
+    if let Some(key) = table.keys().next() {
+        return Err(format!("unknown key {key:?}"));
+    }
+    attempts
+        .iter()
+        .enumerate()
+        .map(|(i, attempt)| {
+            let table = attempt
+                .as_table()
+                .ok_or_else(|| format!("attempt {} should be a table", i + 1))?;
+            Attempt::from_toml(table).map_err(|e| format!("attempt {}: {e}", i + 1))
+        })
+        .collect()
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(page: &str) -> Result<Self, Self::Err> {
+        Outcome::recognise(page).ok_or_else(|| {
+            let text = article_text(page);
+            format!("unrecognised response: {}", text.trim())
+        })
+    }
+}
+
+/// The text of the page's `<article>`, where the site explains itself, with
+/// the markup stripped.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = true;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Refused(Refusal),
+    Client(client::Error),
+    History(HistoryError),
+    /// The site answered, but not in a way this recognises. The attempt is
+    /// not recorded.
+    Unrecognised(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Refused(refusal) => write!(f, "not submitted, {refusal}"),
+            Error::Client(err) => err.fmt(f),
+            Error::History(err) => err.fmt(f),
+            Error::Unrecognised(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Submits an answer unless the history shows it to be pointless, and
+/// records the outcome.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Outcome, Error> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    history
+        .check(day, part, answer, now)
+        .map_err(Error::Refused)?;
+
+    let level = (part as u8 + 1).to_string();
+    let page = client
+        .post(
+            &format!("/{year}/day/{day}/answer"),
+            &[("level", &level), ("answer", &answer.to_string())],
+        )
+        .map_err(Error::Client)?;
+    let outcome: Outcome = page.parse().map_err(Error::Unrecognised)?;
+
+    history
+        .record(Attempt {
+            day,
+            part,
+            answer: answer.clone(),
+            outcome,
+            time: now,
+        })
+        .map_err(Error::History)?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{stub, Config};
+
+    fn page(text: &str) -> String {
+        format!("<html><body><main><article><p>{text}</p></article></main></body></html>")
+    }
+
+    fn attempt(answer: impl Into<Answer>, outcome: Outcome) -> Attempt {
+        Attempt {
+            day: 5,
+            part: Part::Two,
+            answer: answer.into(),
+            outcome,
+            time: 1000,
+        }
+    }
+
+    fn history(name: &str) -> History {
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let path = dir.join(format!("{name}.toml"));
+        let _ = fs::remove_file(&path);
+        History::load(path).unwrap()
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Outcome::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Outcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Outcome::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                Outcome::Wrong,
+            ),
+            (
+                "You gave an answer too recently. You have 1m 5s left to wait.",
+                Outcome::Wait(Duration::from_secs(65)),
+            ),
+            (
+                "You don't seem to be solving the right level.",
+                Outcome::AlreadySolved,
+            ),
+        ];
+        for (text, outcome) in cases {
+            assert_eq!(page(text).parse(), Ok(outcome), "{text}");
+        }
+        assert_eq!(
+            page("Something <em>else</em>.").parse::<Outcome>(),
+            Err("unrecognised response: Something else.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let mut history = history("check");
+        history.attempts = vec![
+            attempt(2_000_000, Outcome::TooHigh),
+            attempt(1000, Outcome::TooLow),
+            attempt(1_500_000, Outcome::Wrong),
+            attempt(1_200_000, Outcome::Wait(Duration::from_secs(60))),
+        ];
+        let check = |answer: i64, now| history.check(5, Part::Two, &Answer::from(answer), now);
+        assert_eq!(
+            check(1_500_000, 2000),
+            Err(Refusal::KnownWrong(Outcome::Wrong))
+        );
+        assert_eq!(
+            check(2_500_000, 2000),
+            Err(Refusal::NotBelowTooHigh(Answer::from(2_000_000)))
+        );
+        assert_eq!(
+            check(999, 2000),
+            Err(Refusal::NotAboveTooLow(Answer::from(1000)))
+        );
+        assert_eq!(
+            check(1_240_035, 1045),
+            Err(Refusal::Wait(Duration::from_secs(15)))
+        );
+        assert_eq!(check(1_240_035, 2000), Ok(()));
+        assert_eq!(
+            history.check(5, Part::One, &Answer::from(2_500_000), 2000),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_record_round_trips() {
+        let mut history = history("record");
+        let attempts = [
+            attempt(1_240_035, Outcome::TooLow),
+            attempt("RFSLJDHNT", Outcome::Wait(Duration::from_secs(30))),
+            attempt(Answer::Number(i128::MAX), Outcome::Correct),
+        ];
+        for attempt in attempts.clone() {
+            history.record(attempt).unwrap();
+        }
+        let loaded = History::load(history.path.clone()).unwrap();
+        assert_eq!(loaded.attempts(), attempts);
+    }
+
+    #[test]
+    fn test_load_error_names_history() {
+        let history = history("corrupt");
+        fs::create_dir_all(history.path.parent().unwrap()).unwrap();
+        fs::write(&history.path, "not toml").unwrap();
+        let error = History::load(history.path.clone()).unwrap_err();
+        assert!(error.to_string().starts_with("submission history "));
+        assert_eq!(error.path, history.path);
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = stub::serve(vec![
+            (
+                200,
+                &page("That's not the right answer; your answer is too high."),
+            ),
+            (200, &page("That's the right answer!")),
+        ]);
+        let client = Client::new(Config {
+            session: Some("cafe".to_string()),
+            base_url: server.base_url.clone(),
+            contact: None,
+        });
+        let mut history = history("submit");
+        let mut submit = |answer: i64| {
+            submit(
+                &client,
+                &mut history,
+                2023,
+                5,
+                Part::Two,
+                &Answer::from(answer),
+            )
+        };
+
+        assert_eq!(submit(2_000_000).unwrap(), Outcome::TooHigh);
+        assert!(matches!(
+            submit(3_000_000),
+            Err(Error::Refused(Refusal::NotBelowTooHigh(_)))
+        ));
+        assert_eq!(submit(1_240_035).unwrap(), Outcome::Correct);
+        assert!(matches!(
+            submit(1_240_035),
+            Err(Error::Refused(Refusal::AlreadyCorrect(_)))
+        ));
+        assert_eq!(history.attempts().len(), 2);
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2023/day/5/answer "));
+        assert!(requests[0].ends_with("level=2&answer=2000000"));
+    }
+}
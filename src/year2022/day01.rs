@@ -0,0 +1,68 @@
+use crate::{
+    parse::{number, ParseError},
+    Answer, Solution,
+};
+
+fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut elves = Vec::new();
+    let mut curr_cal = 0;
+
+    for content in input.lines() {
+        if content.is_empty() {
+            elves.push(curr_cal);
+            curr_cal = 0;
+        } else {
+            let calories: i32 = number(content)?;
+            curr_cal += calories;
+        }
+    }
+    elves.push(curr_cal);
+    Ok(elves)
+}
+
+fn top_three(elves: &[i32]) -> (i32, i32, i32) {
+    let mut first = 0;
+    let mut second = 0;
+    let mut third = 0;
+
+    for &curr_cal in elves {
+        if curr_cal > first {
+            third = second;
+            second = first;
+            first = curr_cal;
+        } else if curr_cal > second {
+            third = second;
+            second = curr_cal;
+        } else if curr_cal > third {
+            third = curr_cal;
+        }
+    }
+    (first, second, third)
+}
+
+fn part_one(elves: &[i32]) -> i32 {
+    top_three(elves).0
+}
+
+fn part_two(elves: &[i32]) -> i32 {
+    let (first, second, third) = top_three(elves);
+    first + second + third
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(elves: &Self::Input) -> Answer {
+        part_one(elves).into()
+    }
+
+    fn part_two(elves: &Self::Input) -> Answer {
+        part_two(elves).into()
+    }
+}
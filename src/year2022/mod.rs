@@ -0,0 +1,7 @@
+//! Solutions for [Advent of Code 2022](https://adventofcode.com/2022).
+
+pub mod day01;
+
+use crate::Day;
+
+pub const DAYS: &[Day] = &[Day::new::<day01::Day01>(2022, 1)];
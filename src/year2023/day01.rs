@@ -1,19 +1,20 @@
 use std::collections::HashMap;
-use std::fs;
 use std::iter::Iterator;
 
-fn part_one(lines: Vec<String>) -> u32 {
+use crate::{parse::ParseError, Answer, Solution};
+
+fn part_one(lines: &[String]) -> u32 {
     let mut sum = 0;
     for line in lines {
         let first_digit = line
             .chars()
-            .find(|c| c.is_digit(10))
+            .find(|c| c.is_ascii_digit())
             .unwrap()
             .to_digit(10)
             .unwrap();
         let last_digit = line
             .chars()
-            .rfind(|c| c.is_digit(10))
+            .rfind(|c| c.is_ascii_digit())
             .unwrap()
             .to_digit(10)
             .unwrap();
@@ -22,7 +23,7 @@ fn part_one(lines: Vec<String>) -> u32 {
     sum
 }
 
-fn part_two(lines: Vec<String>) -> u32 {
+fn part_two(lines: &[String]) -> u32 {
     let map = HashMap::from([
         ("one", 1),
         ("two", 2),
@@ -73,29 +74,20 @@ fn match_number(map: &HashMap<&str, u32>, slice: &str) -> Option<u32> {
     None
 }
 
-fn main() {
-    let lines: Vec<String> = fs::read_to_string("src/input/2023-01.txt")
-        .unwrap()
-        .lines()
-        .map(|s| s.to_string())
-        .collect();
+pub struct Day01;
 
-    println!("{}", part_one(lines.to_owned()));
-    println!("{}", part_two(lines.to_owned()));
-}
+impl Solution for Day01 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|s| s.to_string()).collect())
+    }
 
-#[test]
-fn test_part_two() {
-    let input = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen"
-        .to_string()
-        .split("\n")
-        .map(|s| s.to_string())
-        .collect();
-    assert_eq!(part_two(input), 281);
+    fn part_one(lines: &Self::Input) -> Answer {
+        part_one(lines).into()
+    }
+
+    fn part_two(lines: &Self::Input) -> Answer {
+        part_two(lines).into()
+    }
 }
@@ -1,13 +1,16 @@
-use std::fs;
+use crate::{
+    parse::{number, split_once, ParseError},
+    Answer, Solution,
+};
 
 #[derive(Debug)]
-struct Cubes {
+pub struct Cubes {
     red: u32,
     green: u32,
     blue: u32,
 }
 
-type Game = Vec<Cubes>;
+pub type Game = Vec<Cubes>;
 
 fn part_one(games: &Vec<Game>) -> u32 {
     let red_max: u32 = 12;
@@ -15,15 +18,13 @@ fn part_one(games: &Vec<Game>) -> u32 {
     let blue_max: u32 = 14;
 
     let mut sum = 0;
-    let mut id: u32 = 1;
-    for game in games {
+    for (id, game) in (1..).zip(games) {
         if game
             .iter()
             .all(|cube| cube.red <= red_max && cube.green <= green_max && cube.blue <= blue_max)
         {
             sum += id;
         }
-        id += 1;
     }
     sum
 }
@@ -46,40 +47,53 @@ fn part_two(games: &Vec<Game>) -> u32 {
     sum
 }
 
-fn parse(input: String) -> Vec<Game> {
+fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
     input
         .lines()
         .map(|game| {
-            game.split_once(": ")
-                .unwrap()
+            split_once(game, ": ")?
                 .1
                 .split("; ")
                 .map(|draw| {
                     let mut red = 0;
                     let mut green = 0;
                     let mut blue = 0;
-                    draw.split(", ").for_each(|color| {
-                        let mut cube = color.split(" ");
-                        let value = cube.next().unwrap().parse().unwrap();
-                        let name = cube.next().unwrap();
+                    for color in draw.split(", ") {
+                        let (value, name) = split_once(color, " ")?;
+                        let value = number(value)?;
                         match name {
                             "red" => red = value,
                             "green" => green = value,
                             "blue" => blue = value,
-                            _ => panic!("Unknown color"),
+                            _ => {
+                                return Err(ParseError::new(
+                                    name,
+                                    format!("unknown color {name:?}"),
+                                ))
+                            }
                         }
-                    });
-                    Cubes { red, green, blue }
+                    }
+                    Ok(Cubes { red, green, blue })
                 })
-                .collect::<Vec<Cubes>>()
+                .collect()
         })
-        .collect::<Vec<Game>>()
+        .collect()
 }
 
-fn main() {
-    let input = fs::read_to_string("src/input/2023-02.txt").unwrap();
-    let games = parse(input);
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
 
-    println!("Part one: {}", part_one(&games));
-    println!("Part two: {}", part_two(&games));
+    fn part_one(games: &Self::Input) -> Answer {
+        part_one(games).into()
+    }
+
+    fn part_two(games: &Self::Input) -> Answer {
+        part_two(games).into()
+    }
 }
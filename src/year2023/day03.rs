@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+
+use crate::{grid::Grid, parse::ParseError, point::Point, Answer, Solution};
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, Some)
+}
+
+fn part_one(schematic: &Grid<char>) -> u32 {
+    let mut sum = 0;
+    for y in 0..schematic.height() {
+        let mut is_part = false;
+        let mut part_number = 0;
+        for x in 0..schematic.width() {
+            let p = Point::new(x as i64, y as i64);
+            if let Some(digit) = schematic[p].to_digit(10) {
+                if schematic.neighbours8(p).any(|q| is_symbol(schematic[q])) {
+                    is_part = true;
+                }
+                part_number = part_number * 10 + digit;
+            } else {
+                if is_part {
+                    sum += part_number;
+                }
+                part_number = 0;
+                is_part = false;
+            }
+        }
+        if is_part {
+            sum += part_number;
+        }
+    }
+    sum
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+/// Where the number covering `p` starts.
+fn number_start(schematic: &Grid<char>, mut p: Point) -> Point {
+    let left = Point::new(-1, 0);
+    while schematic.get(p + left).is_some_and(|c| c.is_ascii_digit()) {
+        p += left;
+    }
+    p
+}
+
+fn read_number(schematic: &Grid<char>, mut p: Point) -> u32 {
+    let mut number = 0;
+    while let Some(digit) = schematic.get(p).and_then(|c| c.to_digit(10)) {
+        number = number * 10 + digit;
+        p.x += 1;
+    }
+    number
+}
+
+fn part_two(schematic: &Grid<char>) -> u32 {
+    let mut sum = 0;
+    for (p, &c) in schematic.enumerate() {
+        if c != '*' {
+            continue;
+        }
+        let part_numbers: HashSet<Point> = schematic
+            .neighbours8(p)
+            .filter(|&q| schematic[q].is_ascii_digit())
+            .map(|q| number_start(schematic, q))
+            .collect();
+        if part_numbers.len() == 2 {
+            sum += part_numbers
+                .iter()
+                .map(|&q| read_number(schematic, q))
+                .product::<u32>();
+        }
+    }
+    sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(schematic: &Self::Input) -> Answer {
+        part_one(schematic).into()
+    }
+
+    fn part_two(schematic: &Self::Input) -> Answer {
+        part_two(schematic).into()
+    }
+}
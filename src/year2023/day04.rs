@@ -0,0 +1,81 @@
+use crate::{
+    parse::{number, split_once, ParseError},
+    Answer, Solution,
+};
+
+pub type Card = (Vec<u8>, Vec<u8>);
+
+fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (left, right) = split_once(split_once(line, ": ")?.1, " | ")?;
+            Ok((
+                left.split_whitespace()
+                    .map(number)
+                    .collect::<Result<_, _>>()?,
+                right
+                    .split_whitespace()
+                    .map(number)
+                    .collect::<Result<_, _>>()?,
+            ))
+        })
+        .collect()
+}
+
+fn count_matches(winning_numbers: &[u8], numbers_you_have: &[u8]) -> usize {
+    winning_numbers
+        .iter()
+        .filter(|&winning_number| numbers_you_have.contains(winning_number))
+        .count()
+}
+
+fn part_one(cards: &[Card]) -> u32 {
+    cards
+        .iter()
+        .map(|(winning_numbers, numbers_you_have)| {
+            let matches = count_matches(winning_numbers, numbers_you_have) as u32;
+            if matches == 0 {
+                0
+            } else {
+                2_u32.pow(matches - 1)
+            }
+        })
+        .sum()
+}
+
+fn part_two(cards: &[Card]) -> u32 {
+    let mut stack: Vec<(usize, &Card)> = cards
+        .iter()
+        .enumerate()
+        .map(|(i, card)| (i + 1, card))
+        .collect();
+    let mut total_cards = 0;
+    while let Some((card_number, scratchcards)) = stack.pop() {
+        total_cards += 1;
+        let (winning_numbers, numbers_you_have) = scratchcards;
+        let matches = count_matches(winning_numbers, numbers_you_have);
+        for i in 0..matches {
+            stack.push((card_number + 1 + i, &cards[card_number + i]));
+        }
+    }
+    total_cards
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(cards: &Self::Input) -> Answer {
+        part_one(cards).into()
+    }
+
+    fn part_two(cards: &Self::Input) -> Answer {
+        part_two(cards).into()
+    }
+}
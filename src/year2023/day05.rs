@@ -0,0 +1,364 @@
+use crate::{
+    interval::{Interval, IntervalSet},
+    parse::{number, split_once, strip_prefix, strip_suffix, ParseError},
+    Answer, Solution,
+};
+
+/// A line of a map: the `len` numbers from `source` correspond to those
+/// from `destination`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    pub destination: u64,
+    pub source: u64,
+    pub len: u64,
+}
+
+impl Entry {
+    fn sources(&self) -> Interval<u64> {
+        Interval::with_len(self.source, self.len)
+    }
+
+    fn destinations(&self) -> Interval<u64> {
+        Interval::with_len(self.destination, self.len)
+    }
+}
+
+/// A map from numbers of one category to numbers of another. A number is
+/// sent through the first entry whose sources contain it, or to itself if
+/// there is none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    pub from: String,
+    pub to: String,
+    pub entries: Vec<Entry>,
+}
+
+impl Map {
+    pub fn convert(&self, num: u64) -> u64 {
+        self.entries
+            .iter()
+            .find(|entry| entry.sources().contains(num))
+            .map_or(num, |entry| entry.destination + (num - entry.source))
+    }
+
+    /// Where the map sends each of `nums`, splitting them wherever an entry's
+    /// sources start or end.
+    pub fn image(&self, nums: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let mut unmapped = nums.clone();
+        let mut mapped = Vec::new();
+        for entry in &self.entries {
+            let sources = IntervalSet::from(entry.sources());
+            for piece in unmapped.intersection(&sources).intervals() {
+                let start = entry.destination + (piece.start - entry.source);
+                mapped.push(Interval::with_len(start, piece.len()));
+            }
+            unmapped = unmapped.difference(&sources);
+        }
+        unmapped.union(&mapped.into_iter().collect())
+    }
+
+    /// The numbers that the map sends into `nums`.
+    pub fn preimage(&self, nums: &IntervalSet<u64>) -> IntervalSet<u64> {
+        // The sources of earlier entries, which later ones don't get to map.
+        let mut claimed = IntervalSet::new();
+        let mut preimage = IntervalSet::new();
+        for entry in &self.entries {
+            let sources: IntervalSet<u64> = entry.sources().into();
+            let destinations: IntervalSet<u64> = entry.destinations().into();
+            let back: IntervalSet<u64> = nums
+                .intersection(&destinations)
+                .intervals()
+                .iter()
+                .map(|piece| {
+                    let start = entry.source + (piece.start - entry.destination);
+                    Interval::with_len(start, piece.len())
+                })
+                .collect();
+            preimage = preimage.union(&back.difference(&claimed));
+            claimed = claimed.union(&sources);
+        }
+        preimage.union(&nums.difference(&claimed))
+    }
+}
+
+/// The seeds, and the maps that lead from seeds to every other category in
+/// turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    pub fn maps(&self) -> &[Map] {
+        &self.maps
+    }
+
+    /// The categories in the order the maps lead through them, starting with
+    /// seeds.
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        std::iter::once("seed").chain(self.maps.iter().map(|map| map.to.as_str()))
+    }
+
+    fn position(&self, category: &str) -> Option<usize> {
+        self.categories().position(|c| c == category)
+    }
+
+    /// The seeds read as pairs of the start and length of ranges, as in part
+    /// two.
+    pub fn seed_ranges(&self) -> IntervalSet<u64> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|range| Interval::with_len(range[0], range[1]))
+            .collect()
+    }
+
+    /// The number of category `to` that `num` of category `from` leads to, or
+    /// `None` if either category is unknown or `to` comes before `from`.
+    pub fn convert(&self, from: &str, to: &str, num: u64) -> Option<u64> {
+        let (i, j) = (self.position(from)?, self.position(to)?);
+        let maps = self.maps.get(i..j)?;
+        Some(maps.iter().fold(num, |num, map| map.convert(num)))
+    }
+
+    /// The numbers of category `to` that `nums` of category `from` lead to
+    /// if `to` comes after `from`, or that lead to `nums` if it comes before.
+    /// `None` if either category is unknown.
+    pub fn convert_ranges(
+        &self,
+        from: &str,
+        to: &str,
+        nums: &IntervalSet<u64>,
+    ) -> Option<IntervalSet<u64>> {
+        let (i, j) = (self.position(from)?, self.position(to)?);
+        let nums = nums.clone();
+        Some(if i <= j {
+            self.maps[i..j]
+                .iter()
+                .fold(nums, |nums, map| map.image(&nums))
+        } else {
+            let maps = self.maps[j..i].iter().rev();
+            maps.fold(nums, |nums, map| map.preimage(&nums))
+        })
+    }
+}
+
+fn parse_map(section: &str) -> Result<Map, ParseError> {
+    let mut lines = section.lines();
+    let header = lines.next().unwrap_or_default();
+    let (from, to) = split_once(strip_suffix(header, " map:")?, "-to-")?;
+    let entries = lines
+        .map(|line| {
+            let numbers = line
+                .split_whitespace()
+                .map(number)
+                .collect::<Result<Vec<_>, _>>()?;
+            match numbers[..] {
+                [destination, source, len] => Ok(Entry {
+                    destination,
+                    source,
+                    len,
+                }),
+                _ => Err(ParseError::new(line, "expected three numbers")),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Map {
+        from: from.to_string(),
+        to: to.to_string(),
+        entries,
+    })
+}
+
+fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let mut sections = input.split("\n\n");
+
+    let seeds_line = strip_prefix(sections.next().unwrap_or_default(), "seeds: ")?;
+    let tokens: Vec<&str> = seeds_line.split_whitespace().collect();
+    let seeds = tokens
+        .iter()
+        .map(|token| number(token))
+        .collect::<Result<Vec<u64>, _>>()?;
+    // Part two reads the seeds as pairs of a start and a length.
+    if seeds.is_empty() {
+        return Err(ParseError::end_of(seeds_line, "expected a seed"));
+    }
+    if seeds.len() % 2 != 0 {
+        return Err(ParseError::end_of(
+            seeds_line.trim_end(),
+            "expected the length of the last range of seeds",
+        ));
+    }
+    if let Some(i) = (1..seeds.len()).step_by(2).find(|&i| seeds[i] == 0) {
+        return Err(ParseError::new(
+            tokens[i],
+            "expected a range of at least one seed",
+        ));
+    }
+
+    let mut maps: Vec<Map> = Vec::new();
+    for section in sections {
+        let map = parse_map(section)?;
+        let expected = maps.last().map_or("seed", |last| last.to.as_str());
+        if map.from != expected {
+            let header = section.lines().next().unwrap_or_default();
+            return Err(ParseError::new(
+                header,
+                format!("expected a map from {expected:?}, not {:?}", map.from),
+            ));
+        }
+        maps.push(map);
+    }
+    let last = maps.last().map_or("seed", |last| last.to.as_str());
+    if last != "location" {
+        return Err(ParseError::end_of(
+            input.trim_end(),
+            format!("expected a map from {last:?}, on the way to \"location\""),
+        ));
+    }
+
+    Ok(Almanac { seeds, maps })
+}
+
+fn part_one(almanac: &Almanac) -> u64 {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| {
+            almanac
+                .convert("seed", "location", seed)
+                .expect("parse checked that the maps lead to locations")
+        })
+        .min()
+        .expect("parse checked that there is a seed")
+}
+
+fn part_two(almanac: &Almanac) -> u64 {
+    almanac
+        .convert_ranges("seed", "location", &almanac.seed_ranges())
+        .expect("parse checked that the maps lead to locations")
+        .first()
+        .expect("parse checked that every range has a seed")
+}
+
+/// Part two a seed at a time, which takes minutes on a real input.
+#[cfg(test)]
+fn part_two_slow(almanac: &Almanac) -> u64 {
+    use rayon::prelude::*;
+
+    almanac
+        .seeds
+        .chunks(2)
+        .par_bridge()
+        .map(|range| {
+            let start = range[0];
+            let len = range[1];
+            start..(start + len)
+        })
+        .flatten()
+        .map(|seed| almanac.convert("seed", "location", seed).unwrap())
+        .min()
+        .unwrap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(almanac: &Self::Input) -> Answer {
+        part_one(almanac).into()
+    }
+
+    fn part_two(almanac: &Self::Input) -> Answer {
+        part_two(almanac).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = include_str!("../../examples/2023-05/01.txt");
+
+    #[test]
+    fn test_part_two_slow() {
+        let almanac = parse(EXAMPLE).unwrap();
+        assert_eq!(part_two(&almanac), part_two_slow(&almanac));
+
+        // Seed ranges starting, ending and straddling the edges of entries.
+        let almanac = parse(indoc! {"
+            seeds: 0 5 9 3 20 6
+
+            seed-to-soil map:
+            100 4 6
+            3 10 1
+            50 22 2
+
+            soil-to-location map:
+            10 100 3
+            0 3 1
+        "})
+        .unwrap();
+        assert_eq!(part_two(&almanac), part_two_slow(&almanac));
+    }
+
+    #[test]
+    fn test_convert() {
+        let almanac = parse(EXAMPLE).unwrap();
+        assert_eq!(almanac.categories().count(), 8);
+        assert_eq!(almanac.convert("seed", "location", 82), Some(46));
+        assert_eq!(almanac.convert("soil", "fertilizer", 14), Some(53));
+        assert_eq!(almanac.convert("location", "seed", 46), None);
+        assert_eq!(almanac.convert("seed", "gold", 46), None);
+
+        // Every seed below 100 reaches the humidity range exactly when it is
+        // in the range's preimage.
+        let humidity = IntervalSet::from(Interval::new(40, 70));
+        let seeds = almanac
+            .convert_ranges("humidity", "seed", &humidity)
+            .unwrap();
+        for seed in 0..100 {
+            let reached = almanac.convert("seed", "humidity", seed).unwrap();
+            assert_eq!(seeds.contains(seed), humidity.contains(reached), "{seed}");
+        }
+    }
+
+    #[test]
+    fn test_parse_disconnected() {
+        let err = parse(indoc! {"
+            seeds: 1 2
+
+            seed-to-soil map:
+            1 2 3
+
+            water-to-light map:
+            1 2 3
+        "})
+        .unwrap_err();
+        assert_eq!(err.text, "water-to-light map:");
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        let err = parse("seeds: \n\nseed-to-location map:\n1 2 3\n").unwrap_err();
+        assert_eq!(err.message, "expected a seed");
+        let err = parse("seeds: 1 2 3\n\nseed-to-location map:\n1 2 3\n").unwrap_err();
+        assert_eq!(
+            err.message,
+            "expected the length of the last range of seeds"
+        );
+        let err = parse("seeds: 1 0\n\nseed-to-location map:\n1 2 3\n").unwrap_err();
+        assert_eq!(err.text, "0");
+        let err = parse("seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n").unwrap_err();
+        assert_eq!(
+            err.message,
+            "expected a map from \"soil\", on the way to \"location\""
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    parse::{number, strip_prefix, ParseError},
+    Answer, Solution,
+};
+
+/* Note
+ *
+ * This solution uses the quadratic formula.
+ * Using f32 instead of f64 will produce incorrect results. */
+
+fn parse(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    let mut lines = input.lines();
+    let mut row = |label| {
+        strip_prefix(lines.next().unwrap_or_default(), label)?
+            .split_ascii_whitespace()
+            .map(number)
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let times = row("Time:")?;
+    let distances = row("Distance:")?;
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+fn part_one(races: &[(u64, u64)]) -> u64 {
+    races
+        .iter()
+        .map(|&(time, distance)| {
+            let t = time as f64;
+            let d = distance as f64;
+            let descriminant = (t.powi(2) - 4.0 * d).sqrt();
+            let x1;
+            let x2;
+            if descriminant > 0.0 {
+                x1 = (t - descriminant) / 2.0;
+                x2 = (t + descriminant) / 2.0;
+            } else {
+                x1 = (t + descriminant) / 2.0;
+                x2 = (t - descriminant) / 2.0;
+            }
+            x2.ceil() as u64 - x1.floor() as u64 - 1
+        })
+        .product()
+}
+
+fn part_two(races: &[(u64, u64)]) -> u64 {
+    let time = races
+        .iter()
+        .map(|&(t, _)| t.to_string())
+        .reduce(|a, b| a + &b)
+        .unwrap()
+        .parse()
+        .unwrap();
+    let distance = races
+        .iter()
+        .map(|&(_, d)| d.to_string())
+        .reduce(|a, b| a + &b)
+        .unwrap()
+        .parse()
+        .unwrap();
+    part_one(&[(time, distance)])
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(races: &Self::Input) -> Answer {
+        part_one(races).into()
+    }
+
+    fn part_two(races: &Self::Input) -> Answer {
+        part_two(races).into()
+    }
+}
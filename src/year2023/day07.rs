@@ -1,7 +1,12 @@
-use std::{collections::HashMap, fs};
+use std::collections::HashMap;
+
+use crate::{
+    parse::{number, split_once, ParseError},
+    Answer, Solution,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum Card {
+pub enum Card {
     Joker,
     Two,
     Three,
@@ -19,7 +24,7 @@ enum Card {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum HandType {
+pub enum HandType {
     HighCard,
     OnePair,
     TwoPair,
@@ -29,7 +34,7 @@ enum HandType {
     FiveOfAKind,
 }
 
-type Hand = [Card; 5];
+pub type Hand = [Card; 5];
 
 fn hand_type(hand: &Hand) -> HandType {
     let count = hand.iter().fold(HashMap::new(), |mut map, &card| {
@@ -46,7 +51,7 @@ fn hand_type(hand: &Hand) -> HandType {
         .map(|(_, v)| v)
         .collect::<Vec<_>>();
 
-    if frequencies.len() == 0 {
+    if frequencies.is_empty() {
         return HandType::FiveOfAKind;
     }
 
@@ -65,41 +70,55 @@ fn hand_type(hand: &Hand) -> HandType {
     }
 }
 
-fn parse(input: &str, joker: bool) -> Vec<(Hand, u64)> {
+fn parse(input: &str) -> Result<Vec<(Hand, u64)>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let (left, right) = line.split_once(' ').unwrap();
+            let (left, right) = split_once(line, " ")?;
             let hand = left
-                .chars()
-                .map(|c| match c {
-                    'A' => Card::Ace,
-                    'K' => Card::King,
-                    'Q' => Card::Queen,
-                    'J' if joker => Card::Joker,
-                    'J' => Card::Jack,
-                    'T' => Card::Ten,
-                    '9' => Card::Nine,
-                    '8' => Card::Eight,
-                    '7' => Card::Seven,
-                    '6' => Card::Six,
-                    '5' => Card::Five,
-                    '4' => Card::Four,
-                    '3' => Card::Three,
-                    '2' => Card::Two,
-                    _ => unreachable!(),
+                .char_indices()
+                .map(|(i, c)| match c {
+                    'A' => Ok(Card::Ace),
+                    'K' => Ok(Card::King),
+                    'Q' => Ok(Card::Queen),
+                    'J' => Ok(Card::Jack),
+                    'T' => Ok(Card::Ten),
+                    '9' => Ok(Card::Nine),
+                    '8' => Ok(Card::Eight),
+                    '7' => Ok(Card::Seven),
+                    '6' => Ok(Card::Six),
+                    '5' => Ok(Card::Five),
+                    '4' => Ok(Card::Four),
+                    '3' => Ok(Card::Three),
+                    '2' => Ok(Card::Two),
+                    _ => Err(ParseError::new(
+                        &left[i..i + c.len_utf8()],
+                        format!("unknown card {c:?}"),
+                    )),
                 })
-                .collect::<Vec<_>>()
+                .collect::<Result<Vec<_>, _>>()?
                 .try_into()
-                .unwrap();
-            let bid = right.parse().unwrap();
+                .map_err(|_| ParseError::new(left, "expected a hand of five cards"))?;
+            let bid = number(right)?;
+            Ok((hand, bid))
+        })
+        .collect()
+}
+
+fn with_jokers(game: &[(Hand, u64)]) -> Vec<(Hand, u64)> {
+    game.iter()
+        .map(|&(hand, bid)| {
+            let hand = hand.map(|card| match card {
+                Card::Jack => Card::Joker,
+                card => card,
+            });
             (hand, bid)
         })
         .collect()
 }
 
-fn solve(game: &Vec<(Hand, u64)>) -> u64 {
-    let mut sorted: Vec<([Card; 5], u64)> = game.clone();
+fn solve(game: &[(Hand, u64)]) -> u64 {
+    let mut sorted: Vec<([Card; 5], u64)> = game.to_vec();
     sorted.sort_by_key(|&(hand, _)| (hand_type(&hand), hand));
     sorted
         .iter()
@@ -108,35 +127,27 @@ fn solve(game: &Vec<(Hand, u64)>) -> u64 {
         .sum()
 }
 
-fn main() {
-    let input = fs::read_to_string("src/input/2023-07.txt").unwrap();
+pub struct Day07;
 
-    println!("Part one: {}", solve(&parse(&input, false)));
-    println!("Part two: {}", solve(&parse(&input, true)));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use indoc::indoc;
+impl Solution for Day07 {
+    type Input = Vec<(Hand, u64)>;
 
-    const EXAMPLE: &'static str = indoc! {"
-        32T3K 765
-        T55J5 684
-        KK677 28
-        KTJJT 220
-        QQQJA 483
-    "};
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
 
-    #[test]
-    fn test_part_one() {
-        assert_eq!(solve(&parse(EXAMPLE, false)), 6440);
+    fn part_one(game: &Self::Input) -> Answer {
+        solve(game).into()
     }
 
-    #[test]
-    fn test_part_two() {
-        assert_eq!(solve(&parse(EXAMPLE, true)), 5905);
+    fn part_two(game: &Self::Input) -> Answer {
+        solve(&with_jokers(game)).into()
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn test_hand_type() {
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use crate::{
+    math,
+    parse::{split_once, strip_prefix, strip_suffix, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug)]
+pub enum Direction {
+    L,
+    R,
+}
+pub type Node = [char; 3];
+pub type Instruction = Vec<Direction>;
+pub type Network = HashMap<Node, [Node; 2]>;
+
+fn parse_node(s: &str) -> Result<Node, ParseError> {
+    s.chars()
+        .collect::<Vec<_>>()
+        .try_into()
+        .map_err(|_| ParseError::new(s, "expected a three-character node"))
+}
+
+fn parse(input: &str) -> Result<(Instruction, Network), ParseError> {
+    let mut iter = input.lines();
+    let line = iter.next().unwrap_or_default();
+    let instruction: Instruction = line
+        .char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(Direction::L),
+            'R' => Ok(Direction::R),
+            _ => Err(ParseError::new(
+                &line[i..i + c.len_utf8()],
+                format!("unexpected {c:?}"),
+            )),
+        })
+        .collect::<Result<_, _>>()?;
+    let mut network = HashMap::new();
+    let mut targets = Vec::new();
+    for line in iter.skip(1) {
+        let (key, value) = split_once(line, " = ")?;
+        let value = strip_suffix(strip_prefix(value, "(")?, ")")?;
+        let (left, right) = split_once(value, ", ")?;
+        let [left_node, right_node] = [parse_node(left)?, parse_node(right)?];
+        network.insert(parse_node(key)?, [left_node, right_node]);
+        targets.extend([(left_node, left), (right_node, right)]);
+    }
+    if let Some((_, text)) = targets
+        .into_iter()
+        .find(|(node, _)| !network.contains_key(node))
+    {
+        return Err(ParseError::new(text, format!("undefined node {text:?}")));
+    }
+    if instruction.is_empty() {
+        return Err(ParseError::new(line, "expected 'L' or 'R'"));
+    }
+    Ok((instruction, network))
+}
+
+/// The steps from `AAA` to `ZZZ`, or 0 for a network without `AAA`, as in
+/// the example for part two.
+fn part_one((instruction, network): &(Instruction, Network)) -> usize {
+    let mut current = ['A', 'A', 'A'];
+    if !network.contains_key(&current) {
+        log::warn!("there is no node AAA to start from");
+        return 0;
+    }
+    let mut count = 0;
+
+    while current != ['Z', 'Z', 'Z'] {
+        for direction in instruction {
+            let &[left, right] = network.get(&current).unwrap();
+            current = match direction {
+                Direction::L => left,
+                Direction::R => right,
+            };
+            count += 1;
+            if current == ['Z', 'Z', 'Z'] {
+                break;
+            }
+        }
+    }
+
+    count
+}
+
+fn part_two((instruction, network): &(Instruction, Network)) -> usize {
+    let starts = network.keys().filter(|&[_, _, x]| *x == 'A').copied();
+
+    let counts = starts.map(|x| {
+        let mut count = 0;
+        let mut current = x;
+        while current[2] != 'Z' {
+            for direction in instruction {
+                let &[left, right] = network.get(&current).unwrap();
+                current = match direction {
+                    Direction::L => left,
+                    Direction::R => right,
+                };
+                count += 1;
+                if current[2] == 'Z' {
+                    break;
+                }
+            }
+        }
+        count
+    });
+
+    math::lcm_all(counts)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Instruction, Network);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(documents: &Self::Input) -> Answer {
+        part_one(documents).into()
+    }
+
+    fn part_two(documents: &Self::Input) -> Answer {
+        part_two(documents).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_parse_undefined_node() {
+        let input = indoc! {"
+            LR
+
+            AAA = (BBB, ZZZ)
+            ZZZ = (ZZZ, ZZZ)
+        "};
+        let error = parse(input).unwrap_err().locate(input);
+        assert_eq!(error.message, "undefined node \"BBB\"");
+        assert_eq!(error.location.unwrap().line, 3);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    parse::{number, ParseError},
+    Answer, Solution,
+};
+
+fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    input
+        .lines()
+        .map(|line| line.split_ascii_whitespace().map(number).collect())
+        .collect()
+}
+
+fn part_one(histories: &[Vec<i64>]) -> i64 {
+    histories
+        .iter()
+        .map(|history| extrapolate(history.as_slice()))
+        .sum()
+}
+
+fn part_two(histories: &[Vec<i64>]) -> i64 {
+    histories
+        .iter()
+        .map(|history| {
+            let mut copy = history.to_owned();
+            copy.reverse();
+            extrapolate(&copy)
+        })
+        .sum()
+}
+
+fn extrapolate(history: &[i64]) -> i64 {
+    let mut arr = history.to_owned();
+
+    let mut i = history.len() - 1;
+    while i > 0 {
+        let mut all_zero = true;
+        for j in 0..i {
+            arr[j] = arr[j + 1] - arr[j];
+            if arr[j] != 0 {
+                all_zero = false;
+            }
+        }
+        if all_zero {
+            break;
+        }
+        i -= 1;
+    }
+
+    arr[i..].iter().sum()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(histories: &Self::Input) -> Answer {
+        part_one(histories).into()
+    }
+
+    fn part_two(histories: &Self::Input) -> Answer {
+        part_two(histories).into()
+    }
+}
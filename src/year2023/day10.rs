@@ -0,0 +1,305 @@
+use std::collections::HashSet;
+
+use crate::{
+    direction::Cardinal,
+    grid::Grid,
+    image::{self, Palette, Picture, Raster},
+    parse::{find_one, ParseError},
+    point::Point,
+    render::{Colour, Renderer},
+    Answer, Part, Solution,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Pipe([Cardinal; 2]),
+    Ground,
+    Start,
+}
+
+const PIPES: [(char, [Cardinal; 2]); 6] = [
+    ('|', [Cardinal::North, Cardinal::South]),
+    ('-', [Cardinal::East, Cardinal::West]),
+    ('L', [Cardinal::North, Cardinal::East]),
+    ('J', [Cardinal::North, Cardinal::West]),
+    ('7', [Cardinal::South, Cardinal::West]),
+    ('F', [Cardinal::South, Cardinal::East]),
+];
+
+impl Tile {
+    /// The character the tile is drawn with in the sketch.
+    fn glyph(&self) -> char {
+        match self {
+            Tile::Pipe(directions) => PIPES
+                .iter()
+                .find(|(_, pipe)| pipe == directions)
+                .map_or('?', |&(c, _)| c),
+            Tile::Ground => '.',
+            Tile::Start => 'S',
+        }
+    }
+}
+
+/// The pipes, and the loop through the start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sketch {
+    pub grid: Grid<Tile>,
+    /// The tiles of the loop in order, ending back at the start.
+    pub the_loop: Vec<Point>,
+}
+
+/// Parses the sketch, checking that it has a single start on a loop.
+fn parse(input: &str) -> Result<Sketch, ParseError> {
+    let grid = Grid::parse(input, |c| match c {
+        '.' => Some(Tile::Ground),
+        'S' => Some(Tile::Start),
+        c => PIPES
+            .iter()
+            .find(|&&(pipe, _)| pipe == c)
+            .map(|&(_, directions)| Tile::Pipe(directions)),
+    })?;
+    let start = find_one(input, 'S')?;
+    let the_loop = find_loop(&grid)
+        .ok_or_else(|| ParseError::new(start, "expected the start to be on a loop"))?;
+    Ok(Sketch { grid, the_loop })
+}
+
+fn part_one(sketch: &Sketch) -> usize {
+    sketch.the_loop.len() / 2
+}
+
+/// The loop through the start, if there is one, ending back at the start.
+fn find_loop(sketch: &Grid<Tile>) -> Option<Vec<Point>> {
+    let start = sketch.find(&Tile::Start)?;
+    let mut stack = vec![(start, Vec::new())];
+    let mut visited = HashSet::new();
+
+    while let Some((position, path)) = stack.pop() {
+        let current = sketch[position];
+        log::trace!(
+            "Popping {current:?} at {position:?} at depth {}",
+            path.len()
+        );
+
+        if current == Tile::Start && path.len() > 2 {
+            return Some(path);
+        }
+        if visited.contains(&position) {
+            continue;
+        }
+        visited.insert(position);
+
+        let directions = match current {
+            Tile::Pipe(d) => d.to_vec(),
+            Tile::Ground => unreachable!(),
+            Tile::Start => Cardinal::ALL.to_vec(),
+        };
+        for direction in directions {
+            log::trace!("Going {direction:?}");
+            let neighbour = position + direction.offset();
+            let Some(&next) = sketch.get(neighbour) else {
+                continue;
+            };
+            if visited.contains(&neighbour) && start != neighbour {
+                continue;
+            }
+            match next {
+                Tile::Pipe(next_directions) => {
+                    if !next_directions.contains(&direction.turn_around()) {
+                        continue;
+                    }
+                }
+                Tile::Ground => continue,
+                Tile::Start => (),
+            }
+
+            log::trace!(
+                "Pushing {next:?} at {neighbour:?} at depth {}",
+                path.len() + 1
+            );
+            let mut new_path = path.clone();
+            new_path.push(neighbour);
+            stack.push((neighbour, new_path));
+        }
+    }
+    None
+}
+
+/// The loop drawn at three times the scale, so that the gaps between
+/// neighbouring pipes become ground that a flood fill can squeeze through.
+fn scale_up(sketch: &Grid<Tile>, the_loop: &[Point]) -> Grid<Tile> {
+    let mut scaled = Grid::new(sketch.width() * 3, sketch.height() * 3, Tile::Ground);
+    for &position in the_loop.iter() {
+        let current = sketch[position];
+        let center = position * 3 + Point::new(1, 1);
+        scaled[center] = current;
+        let directions = match current {
+            Tile::Pipe(directions) => directions.to_vec(),
+            Tile::Start => Cardinal::ALL.to_vec(),
+            _ => vec![],
+        };
+        for direction in directions {
+            scaled[center + direction.offset()] = current;
+        }
+    }
+    scaled
+}
+
+/// The tiles that the loop encloses.
+fn enclosed(sketch: &Sketch) -> Vec<Point> {
+    let Sketch { grid, the_loop } = sketch;
+    let scaled = scale_up(grid, the_loop);
+    let mut stack = vec![Point::ORIGIN];
+    let mut visited = HashSet::new();
+    while let Some(position) = stack.pop() {
+        if visited.contains(&position) {
+            continue;
+        }
+        visited.insert(position);
+
+        for neighbour in scaled.neighbours4(position) {
+            if visited.contains(&neighbour) {
+                continue;
+            }
+            if scaled[neighbour] != Tile::Ground {
+                continue;
+            }
+
+            stack.push(neighbour);
+        }
+    }
+    grid.points()
+        .filter(|&position| {
+            !the_loop.contains(&position) && !visited.contains(&(position * 3 + Point::new(1, 1)))
+        })
+        .collect()
+}
+
+fn part_two(sketch: &Sketch) -> usize {
+    let enclosed = enclosed(sketch);
+    log::debug!(
+        "Loop of {} tiles enclosing {}:\n{}",
+        sketch.the_loop.len(),
+        enclosed.len(),
+        visualize(sketch, &enclosed)
+    );
+    enclosed.len()
+}
+
+/// Draws the sketch with the loop and the tiles it encloses highlighted.
+pub fn visualize<'a>(sketch: &'a Sketch, enclosed: &[Point]) -> Renderer<'a, Tile> {
+    Renderer::new(&sketch.grid, Tile::glyph)
+        .box_drawing(true)
+        .highlight(sketch.the_loop.iter().copied(), Colour::Green)
+        .highlight(enclosed.iter().copied(), Colour::Yellow)
+}
+
+/// Pictures the loop at three pixels a tile, so that its pipes show, with
+/// the tiles it encloses filled in for part two.
+pub fn picture(sketch: &Sketch, part: Part) -> Raster {
+    let scaled = scale_up(&sketch.grid, &sketch.the_loop);
+    let inside: HashSet<Point> = match part {
+        Part::One => HashSet::new(),
+        Part::Two => enclosed(sketch)
+            .into_iter()
+            .flat_map(|position| (0..9).map(move |i| position * 3 + Point::new(i % 3, i / 3)))
+            .collect(),
+    };
+    let cells = scaled.enumerate().map(|(position, &tile)| {
+        if tile != Tile::Ground {
+            'L'
+        } else if inside.contains(&position) {
+            'I'
+        } else {
+            '.'
+        }
+    });
+    let cells = Grid::from_vec(scaled.width(), scaled.height(), cells.collect());
+    let palette = Palette::new(image::WHITE)
+        .with('L', [30, 120, 40])
+        .with('I', [250, 200, 60]);
+    Raster::from_grid(&cells, 2, |c| palette.colour(c))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Sketch;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(sketch: &Self::Input) -> Answer {
+        part_one(sketch).into()
+    }
+
+    fn part_two(sketch: &Self::Input) -> Answer {
+        part_two(sketch).into()
+    }
+
+    fn picture(sketch: &Self::Input, part: Part) -> Option<Picture> {
+        Some(Picture::Raster(picture(sketch, part)))
+    }
+}
+
+#[cfg(test)]
+mod test_find_loop {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../examples/2023-10/01.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            parse(EXAMPLE).unwrap().the_loop,
+            vec![
+                Point::new(1, 2),
+                Point::new(1, 3),
+                Point::new(2, 3),
+                Point::new(3, 3),
+                Point::new(3, 2),
+                Point::new(3, 1),
+                Point::new(2, 1),
+                Point::new(1, 1)
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_parse {
+    use super::*;
+
+    #[test]
+    fn no_start() {
+        let error = parse(".F7\n.LJ\n").unwrap_err();
+        assert_eq!(error.message, "expected a 'S'");
+    }
+
+    #[test]
+    fn start_off_the_loop() {
+        let input = "S.\n..\n";
+        let error = parse(input).unwrap_err().locate(input);
+        assert_eq!(error.message, "expected the start to be on a loop");
+        assert_eq!(error.location.unwrap().column, 1);
+    }
+}
+
+#[cfg(test)]
+mod test_picture {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let sketch = parse(include_str!("../../examples/2023-10/01.txt")).unwrap();
+        let Some(Picture::Raster(raster)) = Day10::picture(&sketch, Part::Two) else {
+            panic!("expected a raster");
+        };
+        // Each tile is scaled up to three cells, drawn two pixels wide.
+        assert_eq!(
+            (raster.width(), raster.height()),
+            (sketch.grid.width() * 6, sketch.grid.height() * 6)
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{grid::Grid, parse::ParseError, Answer, Params, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pixel {
+    Empty,
+    Galaxy,
+}
+
+fn parse(input: &str) -> Result<Grid<Pixel>, ParseError> {
+    Grid::parse(input, |c| match c {
+        '.' => Some(Pixel::Empty),
+        '#' => Some(Pixel::Galaxy),
+        _ => None,
+    })
+}
+
+fn solve(image: &Grid<Pixel>, expansion: usize) -> usize {
+    let row_indices = image
+        .rows()
+        .scan(0, |state, row| {
+            let index = Some(*state);
+            if row.contains(&Pixel::Galaxy) {
+                *state += 1;
+            } else {
+                *state += expansion;
+            }
+            index
+        })
+        .collect::<Vec<_>>();
+    let col_indices = image
+        .columns()
+        .scan(0, |state, mut column| {
+            let index = Some(*state);
+            if column.any(|&pixel| pixel == Pixel::Galaxy) {
+                *state += 1;
+            } else {
+                *state += expansion;
+            }
+            index
+        })
+        .collect::<Vec<_>>();
+    let galaxies = image
+        .enumerate()
+        .filter(|(_, &pixel)| pixel == Pixel::Galaxy)
+        .map(|(p, _)| (row_indices[p.y as usize], col_indices[p.x as usize]))
+        .collect::<Vec<_>>();
+    galaxies
+        .iter()
+        .enumerate()
+        .map(|(i, &(x1, y1))| {
+            galaxies
+                .iter()
+                .enumerate()
+                .map(|(j, &(x2, y2))| {
+                    if i < j {
+                        ((x2 as isize - x1 as isize).abs() + (y2 as isize - y1 as isize).abs())
+                            as usize
+                    } else {
+                        0
+                    }
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+fn part_one(image: &Grid<Pixel>) -> usize {
+    solve(image, 2)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<Pixel>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(image: &Self::Input) -> Answer {
+        part_one(image).into()
+    }
+
+    fn part_two(image: &Self::Input) -> Answer {
+        Self::part_two_with(image, &Params::default())
+    }
+
+    /// The galaxies' expansion factor defaults to the real input's million.
+    fn part_two_with(image: &Self::Input, params: &Params) -> Answer {
+        let expansion = params.get("expansion").unwrap_or(1_000_000);
+        solve(image, expansion as usize).into()
+    }
+}
@@ -1,19 +1,26 @@
 use std::collections::HashMap;
 
-type Record = (Vec<char>, Vec<usize>);
+use crate::{
+    parse::{number, split_once, ParseError},
+    Answer, Solution,
+};
 
-fn parse(input: &str) -> Vec<Record> {
+pub type Record = (Vec<char>, Vec<usize>);
+
+fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let parts = line.split_once(' ').unwrap();
-            let springs = parts.0.chars().collect::<Vec<_>>();
-            let counts = parts
-                .1
-                .split(',')
-                .map(|n| n.parse().unwrap())
-                .collect::<Vec<_>>();
-            (springs, counts)
+            let (springs, counts) = split_once(line, " ")?;
+            if let Some((i, c)) = springs.char_indices().find(|&(_, c)| !"?#.".contains(c)) {
+                return Err(ParseError::new(
+                    &springs[i..i + c.len_utf8()],
+                    "expected '?', '#' or '.'",
+                ));
+            }
+            let springs = springs.chars().collect::<Vec<_>>();
+            let counts = counts.split(',').map(number).collect::<Result<_, _>>()?;
+            Ok((springs, counts))
         })
         .collect()
 }
@@ -81,60 +88,51 @@ fn part_two(records: &[Record]) -> usize {
         .sum()
 }
 
-fn main() {
-    let records = parse(include_str!("../input/2023-12.txt"));
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(records: &Self::Input) -> Answer {
+        part_one(records).into()
+    }
 
-    println!("Part one: {}", part_one(&records));
-    println!("Part two: {}", part_two(&records));
+    fn part_two(records: &Self::Input) -> Answer {
+        part_two(records).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test_count_arrangements() {
-        let record = &parse("?###???????? 3,2,1")[0];
+        let record = &parse("?###???????? 3,2,1").unwrap()[0];
         assert_eq!(count_arrangements(record), 10);
     }
 
     #[test]
     fn test_count_arrangements_simple() {
-        let record = &parse("???.### 1,1,3")[0];
+        let record = &parse("???.### 1,1,3").unwrap()[0];
         assert_eq!(count_arrangements(record), 1);
     }
 
     #[test]
     fn test_count_arrangements_very_simple() {
-        let record = &parse("### 3")[0];
+        let record = &parse("### 3").unwrap()[0];
         assert_eq!(count_arrangements(record), 1);
     }
 
-    const EXAMPLE: &str = indoc! {"
-        ???.### 1,1,3
-        .??..??...?##. 1,1,3
-        ?#?#?#?#?#?#?#? 1,3,1,6
-        ????.#...#... 4,1,1
-        ????.######..#####. 1,6,5
-        ?###???????? 3,2,1"
-    };
-
-    #[test]
-    fn test_part_one() {
-        let records = parse(EXAMPLE);
-        assert_eq!(part_one(&records), 21);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let records = parse(EXAMPLE);
-        assert_eq!(part_two(&records), 525152);
-    }
-
     #[test]
-    fn test_part_two_simple() {
-        let records = parse("????.######..#####. 1,6,5");
-        assert_eq!(part_two(&records), 2500);
+    fn test_parse_rejects_unknown_springs() {
+        let input = "?#é. 1,1";
+        let error = parse(input).unwrap_err().locate(input);
+        assert_eq!(error.text, "é");
+        assert_eq!(error.location.unwrap().column, 3);
     }
 }
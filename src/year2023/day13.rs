@@ -0,0 +1,119 @@
+use crate::{grid::Grid, parse::ParseError, Answer, Solution};
+
+pub type Pattern = Grid<char>;
+
+fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    input.split("\n\n").map(|s| Grid::parse(s, Some)).collect()
+}
+
+fn part_one(patterns: &[Pattern]) -> usize {
+    patterns
+        .iter()
+        .map(|p| {
+            if let Some(&line) = vertical(p).first() {
+                return line;
+            } else if let Some(&line) = horizontal(p).first() {
+                return line * 100;
+            }
+            unreachable!()
+        })
+        .sum()
+}
+
+fn part_two(patterns: &[Pattern]) -> usize {
+    patterns
+        .iter()
+        .map(|p| {
+            let mut q = p.clone();
+            let ov = vertical(p);
+            let oh = horizontal(p);
+            for point in p.points() {
+                q[point] = if q[point] == '.' { '#' } else { '.' };
+                let v = vertical(&q);
+                let h = horizontal(&q);
+                q[point] = if q[point] == '.' { '#' } else { '.' };
+
+                let a = v.iter().find(|&x| !ov.contains(x));
+                let b = h.iter().find(|&x| !oh.contains(x));
+
+                match (a, b) {
+                    (Some(&x), None) => return x,
+                    (None, Some(&y)) => return y * 100,
+                    (Some(_), Some(_)) => unreachable!(),
+                    _ => (),
+                }
+            }
+            unreachable!()
+        })
+        .sum()
+}
+
+fn vertical(pattern: &Pattern) -> Vec<usize> {
+    let mut result = Vec::new();
+    let width = pattern.width();
+    for i in 0..width - 1 {
+        let window = (i + 1).min(width - i - 1);
+        if (0..window).all(|j| {
+            let a = pattern.column(i - j);
+            let b = pattern.column(i + j + 1);
+            a.eq(b)
+        }) {
+            result.push(i + 1);
+        }
+    }
+    result
+}
+
+fn horizontal(pattern: &Pattern) -> Vec<usize> {
+    let mut result = Vec::new();
+    let height = pattern.height();
+    for i in 0..height - 1 {
+        let window = (i + 1).min(height - i - 1);
+        if (0..window).all(|j| pattern.row(i - j) == pattern.row(i + j + 1)) {
+            result.push(i + 1);
+        }
+    }
+    result
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(patterns: &Self::Input) -> Answer {
+        part_one(patterns).into()
+    }
+
+    fn part_two(patterns: &Self::Input) -> Answer {
+        part_two(patterns).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../examples/2023-13/01.txt");
+
+    #[test]
+    fn test_vertical() {
+        assert_eq!(vertical(&parse(EXAMPLE).unwrap()[0]), vec![5]);
+    }
+
+    #[test]
+    fn test_horizontal() {
+        assert_eq!(horizontal(&parse(EXAMPLE).unwrap()[1]), vec![4]);
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(parse("").unwrap_err().message, "expected a grid");
+        let err = parse("#.\n.#\n\n").unwrap_err();
+        assert_eq!(err.message, "expected a grid");
+    }
+}
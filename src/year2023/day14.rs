@@ -0,0 +1,151 @@
+use crate::{
+    cycle,
+    grid::Grid,
+    image::{self, Palette, Picture, Raster},
+    parse::ParseError,
+    point::Point,
+    record, Answer, Part, Solution,
+};
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| "O#.".contains(c).then_some(c))
+}
+
+fn tilt(platform: &mut Grid<char>) {
+    for col in 0..platform.width() as i64 {
+        let mut slot = 0;
+        for row in 0..platform.height() as i64 {
+            let curr = Point::new(col, row);
+            match platform[curr] {
+                'O' => {
+                    platform.swap(curr, Point::new(col, slot));
+                    slot += 1;
+                }
+                '#' => slot = row + 1,
+                '.' => (),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+fn total_load(platform: &Grid<char>) -> usize {
+    platform
+        .rows()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .map(|&c| if c == 'O' { platform.height() - i } else { 0 })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+fn spin(platform: &mut Grid<char>) {
+    for _ in 0..4 {
+        tilt(platform);
+        *platform = platform.rotate_clockwise();
+    }
+}
+
+fn part_one(platform: &Grid<char>) -> usize {
+    let mut platform = platform.clone();
+    tilt(&mut platform);
+    total_load(&platform)
+}
+
+fn draw(platform: &Grid<char>) -> Raster {
+    let palette = Palette::new(image::WHITE)
+        .with('O', [180, 110, 50])
+        .with('#', [70, 70, 80]);
+    Raster::from_grid(platform, 8, |c| palette.colour(c))
+}
+
+/// Pictures the platform after tilting it north for part one, or after a
+/// billion spin cycles for part two.
+pub fn picture(platform: &Grid<char>, part: Part) -> Raster {
+    match part {
+        Part::One => {
+            let mut platform = platform.clone();
+            tilt(&mut platform);
+            draw(&platform)
+        }
+        Part::Two => draw(&spun(platform)),
+    }
+}
+
+/// The platform after a billion spin cycles, as in part two.
+fn spun(platform: &Grid<char>) -> Grid<char> {
+    let cycles = cycle::find(platform.clone(), |platform| {
+        let mut platform = platform.clone();
+        spin(&mut platform);
+        record::frame(|| draw(&platform));
+        platform
+    });
+    cycles.get(1_000_000_000).clone()
+}
+
+fn part_two(platform: &Grid<char>) -> usize {
+    total_load(&spun(platform))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(platform: &Self::Input) -> Answer {
+        part_one(platform).into()
+    }
+
+    fn part_two(platform: &Self::Input) -> Answer {
+        part_two(platform).into()
+    }
+
+    fn picture(platform: &Self::Input, part: Part) -> Option<Picture> {
+        Some(Picture::Raster(picture(platform, part)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = include_str!("../../examples/2023-14/01.txt");
+
+    #[test]
+    fn test_tilt() {
+        let mut platform = parse(EXAMPLE).unwrap();
+        tilt(&mut platform);
+        assert_eq!(
+            platform,
+            parse(indoc! {"
+                OOOO.#.O..
+                OO..#....#
+                OO..O##..O
+                O..#.OO...
+                ........#.
+                ..#....#.#
+                ..O..#.O.O
+                ..O.......
+                #....###..
+                #....#....
+                "})
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_picture() {
+        let platform = parse(EXAMPLE).unwrap();
+        let Some(Picture::Raster(raster)) = Day14::picture(&platform, Part::Two) else {
+            panic!("expected a raster");
+        };
+        assert_eq!((raster.width(), raster.height()), (80, 80));
+    }
+}
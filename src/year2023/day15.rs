@@ -0,0 +1,124 @@
+use std::collections::VecDeque;
+
+use crate::{
+    parse::{number, split_once, ParseError},
+    Answer, Solution,
+};
+
+fn hash(input: &str) -> u8 {
+    let mut current_value = 0;
+    for c in input.bytes() {
+        let mut temp = current_value as u32 + c as u32;
+        temp *= 17;
+        current_value = (temp % 256) as u8;
+    }
+    current_value
+}
+
+/// A step of the initialization sequence: its text, which part one hashes,
+/// and what it does to the boxes in part two.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    text: String,
+    operation: Operation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    /// `label-`
+    Remove(String),
+    /// `label=focal_length`
+    Insert(String, u8),
+}
+
+fn parse_step(step: &str) -> Result<Step, ParseError> {
+    let operation = match step.strip_suffix('-') {
+        Some(label) => Operation::Remove(label.to_string()),
+        None => {
+            let (label, focal_length) = split_once(step, "=")?;
+            Operation::Insert(label.to_string(), number(focal_length)?)
+        }
+    };
+    Ok(Step {
+        text: step.to_string(),
+        operation,
+    })
+}
+
+fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+    let sequence = input.lines().next().unwrap_or_default();
+    sequence.split(',').map(parse_step).collect()
+}
+
+fn part_one(steps: &[Step]) -> u32 {
+    steps.iter().map(|step| hash(&step.text) as u32).sum()
+}
+
+fn part_two(steps: &[Step]) -> u32 {
+    let mut boxes: [VecDeque<(&str, u8)>; 256] = std::array::from_fn(|_| VecDeque::new());
+    for step in steps {
+        match &step.operation {
+            Operation::Remove(label) => {
+                let relevant_box = &mut boxes[hash(label) as usize];
+                if let Some(index) = relevant_box.iter().position(|&(l, _)| l == label) {
+                    relevant_box.remove(index);
+                }
+            }
+            Operation::Insert(label, focal_length) => {
+                let relevant_box = &mut boxes[hash(label) as usize];
+                if let Some(index) = relevant_box.iter().position(|&(l, _)| l == label) {
+                    relevant_box[index].1 = *focal_length;
+                } else {
+                    relevant_box.push_back((label, *focal_length));
+                }
+            }
+        }
+    }
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            b.iter()
+                .enumerate()
+                .map(|(j, &(_, f))| (i as u32 + 1) * (j as u32 + 1) * f as u32)
+                .sum::<u32>()
+        })
+        .sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(steps: &Self::Input) -> Answer {
+        part_one(steps).into()
+    }
+
+    fn part_two(steps: &Self::Input) -> Answer {
+        part_two(steps).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52);
+    }
+
+    #[test]
+    fn test_parse() {
+        let steps = parse("rn=1,cm-").unwrap();
+        assert_eq!(steps[0].operation, Operation::Insert("rn".to_string(), 1));
+        assert_eq!(steps[1].operation, Operation::Remove("cm".to_string()));
+        assert!(parse("rn=x").is_err());
+        assert!(parse("rn").is_err());
+    }
+}
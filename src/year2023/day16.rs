@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+
+use crate::{
+    direction::Cardinal,
+    grid::Grid,
+    image::{self, Palette, Picture, Raster},
+    parse::ParseError,
+    point::Point,
+    record,
+    render::{Colour, Renderer},
+    Answer, Part, Solution,
+};
+
+fn interact(direction: Cardinal, tile: char) -> Vec<Cardinal> {
+    match tile {
+        '.' => vec![direction],
+        '/' => match direction {
+            Cardinal::North => vec![Cardinal::East],
+            Cardinal::South => vec![Cardinal::West],
+            Cardinal::West => vec![Cardinal::South],
+            Cardinal::East => vec![Cardinal::North],
+        },
+        '\\' => match direction {
+            Cardinal::North => vec![Cardinal::West],
+            Cardinal::South => vec![Cardinal::East],
+            Cardinal::West => vec![Cardinal::North],
+            Cardinal::East => vec![Cardinal::South],
+        },
+        '|' => match direction {
+            Cardinal::North | Cardinal::South => vec![direction],
+            Cardinal::West | Cardinal::East => vec![Cardinal::North, Cardinal::South],
+        },
+        '-' => match direction {
+            Cardinal::West | Cardinal::East => vec![direction],
+            Cardinal::North | Cardinal::South => vec![Cardinal::West, Cardinal::East],
+        },
+        _ => unreachable!(),
+    }
+}
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| r"./\|-".contains(c).then_some(c))
+}
+
+/// Follows the beam entering at `position` heading in `direction` until it
+/// only retraces itself, recording a frame each time it advances a tile.
+fn shoot(position: Point, direction: Cardinal, grid: &Grid<char>) -> HashSet<(Point, Cardinal)> {
+    let mut visited = HashSet::from([(position, direction)]);
+    let mut front = vec![(position, direction)];
+    while !front.is_empty() {
+        record::frame(|| draw(grid, &visited));
+        front = front
+            .into_iter()
+            .flat_map(|(position, direction)| {
+                interact(direction, grid[position])
+                    .into_iter()
+                    .map(move |d| (position + d.offset(), d))
+            })
+            .filter(|&(next, d)| grid.contains(next) && visited.insert((next, d)))
+            .collect();
+    }
+    visited
+}
+
+fn energized(visited: &HashSet<(Point, Cardinal)>) -> usize {
+    HashSet::<Point>::from_iter(visited.iter().map(|&(position, _)| position)).len()
+}
+
+fn part_one(grid: &Grid<char>) -> usize {
+    let visited = shoot(Point::ORIGIN, Cardinal::East, grid);
+    log::debug!("Energized tiles:\n{}", visualize(grid, &visited));
+    energized(&visited)
+}
+
+/// Draws the contraption with the tiles that a beam passes through
+/// highlighted.
+pub fn visualize<'a>(
+    grid: &'a Grid<char>,
+    visited: &HashSet<(Point, Cardinal)>,
+) -> Renderer<'a, char> {
+    Renderer::new(grid, |&c| c).highlight(
+        visited.iter().map(|&(position, _)| position),
+        Colour::Yellow,
+    )
+}
+
+/// Pictures a part's most energizing beam as a heatmap of how many
+/// directions it passes through each tile in, with the mirrors and splitters
+/// it misses in grey.
+pub fn picture(grid: &Grid<char>, part: Part) -> Raster {
+    let visited = match part {
+        Part::One => shoot(Point::ORIGIN, Cardinal::East, grid),
+        Part::Two => starts(grid)
+            .into_iter()
+            .map(|(position, direction)| shoot(position, direction, grid))
+            .max_by_key(energized)
+            .expect("a grid has an edge to start from"),
+    };
+    draw(grid, &visited)
+}
+
+fn draw(grid: &Grid<char>, visited: &HashSet<(Point, Cardinal)>) -> Raster {
+    let mut beams = grid.map(|_| 0);
+    for &(position, _) in visited {
+        beams[position] += 1;
+    }
+    // Tiles the beam passes through become the number of directions it
+    // passes in, from 1 to 4, and the rest stay as they are.
+    let cells = grid
+        .enumerate()
+        .map(|(position, &tile)| match beams[position] {
+            0 => tile,
+            beams => char::from_digit(beams, 10).unwrap_or('4'),
+        });
+    let cells = Grid::from_vec(grid.width(), grid.height(), cells.collect());
+    let palette = Palette::new([110, 110, 110])
+        .with('.', image::BLACK)
+        .with('1', image::heat(0.25))
+        .with('2', image::heat(0.5))
+        .with('3', image::heat(0.75))
+        .with('4', image::heat(1.0));
+    Raster::from_grid(&cells, 4, |c| palette.colour(c))
+}
+
+/// Every tile on the edge of the grid, heading into it.
+fn starts(grid: &Grid<char>) -> Vec<(Point, Cardinal)> {
+    let (width, height) = (grid.width() as i64, grid.height() as i64);
+    let mut starts = Vec::new();
+    for i in 0..height {
+        starts.push((Point::new(0, i), Cardinal::East));
+        starts.push((Point::new(width - 1, i), Cardinal::West));
+    }
+    for i in 0..width {
+        starts.push((Point::new(i, 0), Cardinal::South));
+        starts.push((Point::new(i, height - 1), Cardinal::North));
+    }
+    starts
+}
+
+fn part_two(grid: &Grid<char>) -> usize {
+    let mut max = 0;
+    for (position, direction) in starts(grid) {
+        max = max.max(energized(&shoot(position, direction, grid)));
+    }
+    max
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(grid: &Self::Input) -> Answer {
+        part_one(grid).into()
+    }
+
+    fn part_two(grid: &Self::Input) -> Answer {
+        part_two(grid).into()
+    }
+
+    fn picture(grid: &Self::Input, part: Part) -> Option<Picture> {
+        Some(Picture::Raster(picture(grid, part)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../examples/2023-16/01.txt");
+
+    #[test]
+    fn test_visualize() {
+        let grid = parse(EXAMPLE).unwrap();
+        let visited = shoot(Point::ORIGIN, Cardinal::East, &grid);
+        let plain = visualize(&grid, &visited).ansi(false).to_string();
+        assert_eq!(plain, EXAMPLE);
+        // The beam crosses the first row as far as the mirror.
+        let ansi = visualize(&grid, &visited).ansi(true).to_string();
+        assert!(ansi.starts_with("\x1b[0;43m.|...\\\x1b[0m....\n"));
+    }
+
+    #[test]
+    fn test_picture() {
+        let grid = parse(EXAMPLE).unwrap();
+        let Some(Picture::Raster(raster)) = Day16::picture(&grid, Part::One) else {
+            panic!("expected a raster");
+        };
+        assert_eq!((raster.width(), raster.height()), (40, 40));
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(parse("").unwrap_err().message, "expected a grid");
+        assert_eq!(parse("./x\n").unwrap_err().text, "x");
+    }
+}
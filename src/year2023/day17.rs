@@ -0,0 +1,147 @@
+use pathfinding::directed::dijkstra::dijkstra;
+
+use crate::{
+    direction::Cardinal,
+    grid::Grid,
+    parse::ParseError,
+    point::Point,
+    render::{Colour, Renderer},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct State {
+    position: Point,
+    direction: Cardinal,
+    straight: u8,
+}
+
+impl State {
+    fn successors(&self, map: &Grid<u32>, min: u8, max: u8) -> Vec<(State, u32)> {
+        let mut successors = Vec::new();
+        let mut directions = Vec::new();
+        if self.straight >= min {
+            directions.extend([self.direction.turn_left(), self.direction.turn_right()]);
+        }
+        if self.straight < max {
+            directions.push(self.direction);
+        }
+        for direction in directions {
+            let position = self.position + direction.offset();
+            let Some(&heat_loss) = map.get(position) else {
+                continue;
+            };
+            successors.push((
+                State {
+                    position,
+                    direction,
+                    straight: if direction == self.direction {
+                        self.straight + 1
+                    } else {
+                        1
+                    },
+                },
+                heat_loss,
+            ));
+        }
+        successors
+    }
+
+    fn success(&self, map: &Grid<u32>) -> bool {
+        self.position == Point::new(map.width() as i64 - 1, map.height() as i64 - 1)
+    }
+}
+
+fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10))
+}
+
+/// The path of least heat loss for a crucible that starts off heading in
+/// `direction` and must move between `min` and `max` blocks in a straight line,
+/// if it can get to the bottom right corner at all.
+fn least_heat_loss(
+    map: &Grid<u32>,
+    direction: Cardinal,
+    min: u8,
+    max: u8,
+) -> Option<(Vec<State>, u32)> {
+    let start = State {
+        position: Point::ORIGIN,
+        direction,
+        straight: 0,
+    };
+    let goal = |state: &State| state.success(map);
+    let successors = |state: &State| state.successors(map, min, max);
+    dijkstra(&start, successors, goal)
+}
+
+fn part_one(map: &Grid<u32>) -> u32 {
+    let (path, heat_loss) = least_heat_loss(map, Cardinal::South, 0, 3)
+        .expect("a crucible that can turn anywhere reaches every block");
+    log::debug!("Crucible path:\n{}", visualize(map, &path));
+    heat_loss
+}
+
+fn part_two(map: &Grid<u32>) -> u32 {
+    [Cardinal::South, Cardinal::East]
+        .iter()
+        .filter_map(|&direction| least_heat_loss(map, direction, 4, 10))
+        .map(|(_, heat_loss)| heat_loss)
+        .min()
+        .unwrap_or_else(|| {
+            log::warn!("an ultra crucible cannot reach the bottom right corner");
+            0
+        })
+}
+
+/// Draws the map with a crucible's path highlighted.
+pub fn visualize<'a>(map: &'a Grid<u32>, path: &[State]) -> Renderer<'a, u32> {
+    Renderer::new(map, |&heat_loss| {
+        char::from_digit(heat_loss, 10).unwrap_or('?')
+    })
+    .highlight(path.iter().map(|state| state.position), Colour::Red)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(map: &Self::Input) -> Answer {
+        part_one(map).into()
+    }
+
+    fn part_two(map: &Self::Input) -> Answer {
+        part_two(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../examples/2023-17/01.txt");
+
+    #[test]
+    fn test_visualize() {
+        let map = parse(EXAMPLE).unwrap();
+        let (path, _) = least_heat_loss(&map, Cardinal::South, 0, 3).unwrap();
+        let plain = visualize(&map, &path).ansi(false).to_string();
+        assert_eq!(plain, EXAMPLE);
+        // The path comes into the bottom right corner from the left.
+        let ansi = visualize(&map, &path).ansi(true).to_string();
+        assert!(ansi.ends_with("\x1b[0;41m33\x1b[0m\n"));
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(parse("").unwrap_err().message, "expected a grid");
+        // Too narrow for an ultra crucible to turn before it has to.
+        let column = parse(&"1\n".repeat(12)).unwrap();
+        assert_eq!(part_two(&column), 0);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    direction::Cardinal,
+    image::{self, Picture},
+    parse::{number, split_once, strip_prefix, strip_suffix, ParseError},
+    point::Point,
+    Answer, Part, Solution,
+};
+
+pub type Dig = (Cardinal, i64, u32);
+
+fn parse(input: &str) -> Result<Vec<Dig>, ParseError> {
+    let commands: Vec<Dig> = input
+        .lines()
+        .map(|line| {
+            let (direction, rest) = split_once(line, " ")?;
+            let (meters, color) = split_once(rest, " ")?;
+            let color = strip_suffix(strip_prefix(color, "(#")?, ")")?;
+            let direction = match direction {
+                "U" => Cardinal::North,
+                "D" => Cardinal::South,
+                "L" => Cardinal::West,
+                "R" => Cardinal::East,
+                _ => return Err(ParseError::new(direction, "expected one of U, D, L or R")),
+            };
+            Ok((
+                direction,
+                number(meters)?,
+                u32::from_str_radix(color, 16)
+                    .map_err(|_| ParseError::new(color, "expected a hex color"))?,
+            ))
+        })
+        .collect::<Result<_, _>>()?;
+    if commands.is_empty() {
+        return Err(ParseError::end_of(input, "expected a dig plan"));
+    }
+    Ok(commands)
+}
+
+/// The corners of the trench, ending back at the start.
+fn vertices(commands: &[Dig]) -> Vec<Point> {
+    let mut position = Point::ORIGIN;
+    let mut vertices = commands
+        .iter()
+        .map(|&(dir, len, ..)| {
+            position += dir.offset() * len;
+            position
+        })
+        .collect::<Vec<_>>();
+    vertices.push(vertices[0]);
+    vertices
+}
+
+fn solve(commands: &[Dig]) -> i64 {
+    let t = 2 + commands.iter().map(|&(_, len, _)| len).sum::<i64>();
+    (vertices(commands)
+        .windows(2)
+        .map(|i| i[0].x * i[1].y - i[0].y * i[1].x)
+        .sum::<i64>()
+        + t)
+        / 2
+}
+
+/// The real dig plan, hidden in the colours.
+fn decode(commands: &[Dig]) -> Vec<Dig> {
+    commands
+        .iter()
+        .map(|&(_, _, color)| {
+            let direction = [
+                Cardinal::East,
+                Cardinal::South,
+                Cardinal::West,
+                Cardinal::North,
+            ][color as usize % 16];
+            (direction, color as i64 / 16, 0)
+        })
+        .collect()
+}
+
+fn part_one(commands: &[Dig]) -> i64 {
+    solve(commands)
+}
+
+fn part_two(commands: &[Dig]) -> i64 {
+    solve(&decode(commands))
+}
+
+/// Pictures the outline of the lagoon that a part's dig plan digs, as SVG.
+pub fn picture(commands: &[Dig], part: Part) -> String {
+    let vertices = match part {
+        Part::One => vertices(commands),
+        Part::Two => vertices(&decode(commands)),
+    };
+    image::polygon_svg(&vertices, 800, [60, 40, 30], Some([150, 110, 80]))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Dig>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(commands: &Self::Input) -> Answer {
+        part_one(commands).into()
+    }
+
+    fn part_two(commands: &Self::Input) -> Answer {
+        part_two(commands).into()
+    }
+
+    fn picture(commands: &Self::Input, part: Part) -> Option<Picture> {
+        Some(Picture::Svg(picture(commands, part)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_picture() {
+        let commands = parse(include_str!("../../examples/2023-18/01.txt")).unwrap();
+        let Some(Picture::Svg(svg)) = Day18::picture(&commands, Part::One) else {
+            panic!("expected an SVG");
+        };
+        // The outline runs through the middle of the trench, 6 by 9 metres.
+        assert!(svg.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="533" height="800" viewBox="0 0 6 9">"#
+        ));
+
+        // Part two's plan, hidden in the colours, digs over a million metres.
+        let Some(Picture::Svg(svg)) = Day18::picture(&commands, Part::Two) else {
+            panic!("expected an SVG");
+        };
+        assert!(svg.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="800" height="800" viewBox="0 0 1186328 1186328">"#
+        ));
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(parse("").unwrap_err().message, "expected a dig plan");
+        assert_eq!(parse("R 6 (#70c71z)\n").unwrap_err().text, "70c71z");
+        for direction in ["NE", "N", "^"] {
+            let err = parse(&format!("{direction} 6 (#70c710)\n")).unwrap_err();
+            assert_eq!(err.message, "expected one of U, D, L or R");
+        }
+    }
+}
@@ -0,0 +1,1060 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    interval::Interval,
+    parse::{number, split_once, strip_prefix, strip_suffix, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Category {
+    pub const ALL: [Category; 4] = [Category::X, Category::M, Category::A, Category::S];
+
+    fn name(self) -> char {
+        match self {
+            Category::X => 'x',
+            Category::M => 'm',
+            Category::A => 'a',
+            Category::S => 's',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Part {
+    x: u64,
+    m: u64,
+    a: u64,
+    s: u64,
+}
+
+impl Index<Category> for Part {
+    type Output = u64;
+
+    fn index(&self, category: Category) -> &Self::Output {
+        match category {
+            Category::X => &self.x,
+            Category::M => &self.m,
+            Category::A => &self.a,
+            Category::S => &self.s,
+        }
+    }
+}
+
+impl IndexMut<Category> for Part {
+    fn index_mut(&mut self, category: Category) -> &mut Self::Output {
+        match category {
+            Category::X => &mut self.x,
+            Category::M => &mut self.m,
+            Category::A => &mut self.a,
+            Category::S => &mut self.s,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Rule {
+    GreaterThan(Category, u64, Decision),
+    LessThan(Category, u64, Decision),
+    Unconditional(Decision),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Decision {
+    SendTo(String),
+    Accept,
+    Reject,
+}
+
+impl Rule {
+    pub fn decision(&self) -> &Decision {
+        match self {
+            Rule::GreaterThan(_, _, decision)
+            | Rule::LessThan(_, _, decision)
+            | Rule::Unconditional(decision) => decision,
+        }
+    }
+
+    pub fn matches(&self, part: &Part) -> bool {
+        match *self {
+            Rule::GreaterThan(category, value, _) => part[category] > value,
+            Rule::LessThan(category, value, _) => part[category] < value,
+            Rule::Unconditional(_) => true,
+        }
+    }
+
+    /// Splits `region` into the parts that the rule matches and the rest.
+    pub fn split(&self, region: &Region) -> (Region, Region) {
+        match *self {
+            Rule::GreaterThan(category, value, _) => match value.checked_add(1) {
+                Some(above) => {
+                    let (rest, matched) = region.split_at(category, above);
+                    (matched, rest)
+                }
+                None => (Region::empty(), region.clone()),
+            },
+            Rule::LessThan(category, value, _) => region.split_at(category, value),
+            Rule::Unconditional(_) => (region.clone(), Region::empty()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    /// The workflows this one sends parts to, each once, in order.
+    fn targets(&self) -> Vec<&str> {
+        let mut targets = Vec::new();
+        for rule in &self.rules {
+            if let Decision::SendTo(target) = rule.decision() {
+                if !targets.contains(&target.as_str()) {
+                    targets.push(target.as_str());
+                }
+            }
+        }
+        targets
+    }
+}
+
+/// A set of workflows that sorts every part: there is an `in` to start from,
+/// every workflow sent to exists, none of them send parts round in circles,
+/// and each has a rule for every part that reaches it. Only workflows that
+/// [`lint`] finds no errors in can be made into one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workflows {
+    workflows: Vec<Workflow>,
+    index: HashMap<String, usize>,
+}
+
+impl Workflows {
+    /// Checks the workflows, refusing them with the first error [`lint`]
+    /// finds.
+    pub fn new(workflows: Vec<Workflow>) -> Result<Self, Lint> {
+        match lint(&workflows).into_iter().find(Lint::is_error) {
+            Some(lint) => Err(lint),
+            None => Ok(Workflows::checked(workflows)),
+        }
+    }
+
+    /// Workflows that are already known to have no errors.
+    fn checked(workflows: Vec<Workflow>) -> Self {
+        let index = workflows
+            .iter()
+            .enumerate()
+            .map(|(i, workflow)| (workflow.name.clone(), i))
+            .collect();
+        Workflows { workflows, index }
+    }
+
+    /// The workflow named `name`, which any rule here may send parts to.
+    fn get(&self, name: &str) -> &Workflow {
+        &self.workflows[self.index[name]]
+    }
+
+    pub fn as_slice(&self) -> &[Workflow] {
+        &self.workflows
+    }
+}
+
+/// Written as in the puzzle input, e.g. `a<2006:qkq`.
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::GreaterThan(category, value, decision) => {
+                write!(f, "{}>{value}:{decision}", category.name())
+            }
+            Rule::LessThan(category, value, decision) => {
+                write!(f, "{}<{value}:{decision}", category.name())
+            }
+            Rule::Unconditional(decision) => write!(f, "{decision}"),
+        }
+    }
+}
+
+impl fmt::Display for Decision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Decision::SendTo(name) => write!(f, "{name}"),
+            Decision::Accept => write!(f, "A"),
+            Decision::Reject => write!(f, "R"),
+        }
+    }
+}
+
+/// Written as in the puzzle input, e.g. `px{a<2006:qkq,m>2090:A,rfg}`.
+impl fmt::Display for Workflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{{", self.name)?;
+        for (i, rule) in self.rules.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{rule}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// The parts whose rating in each category is in an interval: a box in the
+/// four dimensions of the categories.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Region([Interval<u64>; 4]);
+
+impl Region {
+    /// Every part, with each rating from 1 to 4000.
+    pub fn full() -> Self {
+        Region([Interval::closed(1, 4000); 4])
+    }
+
+    pub fn empty() -> Self {
+        Region([Interval::new(0, 0); 4])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().any(Interval::is_empty)
+    }
+
+    /// How many parts there are in the region.
+    pub fn volume(&self) -> u64 {
+        self.0.iter().map(Interval::len).product()
+    }
+
+    pub fn contains(&self, part: &Part) -> bool {
+        Category::ALL
+            .into_iter()
+            .all(|category| self[category].contains(part[category]))
+    }
+
+    /// The parts in both regions.
+    pub fn intersection(&self, other: &Region) -> Region {
+        let mut both = self.clone();
+        for category in Category::ALL {
+            both[category] = self[category].intersection(&other[category]);
+        }
+        both
+    }
+
+    /// The parts rated below `value` in `category`, and the rest.
+    pub fn split_at(&self, category: Category, value: u64) -> (Region, Region) {
+        let (below, above) = self[category].split_at(value);
+        let (mut low, mut high) = (self.clone(), self.clone());
+        low[category] = below;
+        high[category] = above;
+        (low, high)
+    }
+}
+
+impl Index<Category> for Region {
+    type Output = Interval<u64>;
+
+    fn index(&self, category: Category) -> &Self::Output {
+        &self.0[category as usize]
+    }
+}
+
+impl IndexMut<Category> for Region {
+    fn index_mut(&mut self, category: Category) -> &mut Self::Output {
+        &mut self.0[category as usize]
+    }
+}
+
+/// Written as the ranges of ratings, e.g. `{x=1..=1415,m=1..=4000,...}`.
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, category) in Category::ALL.into_iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            let interval = self[category];
+            match interval.last() {
+                Some(last) => write!(f, "{}={}..={last}", category.name(), interval.start)?,
+                None => write!(f, "{}=none", category.name())?,
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
+/// A rule that fired for a region: the `rule`th, counting from 0, of the
+/// workflow `workflow`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Step {
+    pub workflow: String,
+    pub rule: usize,
+}
+
+/// A region of parts that are all accepted or all rejected, and the rules
+/// that decided it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Classified {
+    pub region: Region,
+    pub decision: Decision,
+    pub path: Vec<Step>,
+}
+
+fn parse_workflow(input: &str) -> Result<Workflow, ParseError> {
+    let (name, rules) = split_once(input, "{")?;
+    let name = name.trim();
+    let rules = strip_suffix(rules, "}")?
+        .split(',')
+        .map(parse_rule)
+        .collect::<Result<_, _>>()?;
+    Ok(Workflow {
+        name: name.to_string(),
+        rules,
+    })
+}
+
+fn parse_category(input: &str) -> Result<Category, ParseError> {
+    match input {
+        "x" => Ok(Category::X),
+        "m" => Ok(Category::M),
+        "a" => Ok(Category::A),
+        "s" => Ok(Category::S),
+        _ => Err(ParseError::new(input, "expected one of x, m, a or s")),
+    }
+}
+
+fn parse_rule(input: &str) -> Result<Rule, ParseError> {
+    Ok(match input {
+        "A" => Rule::Unconditional(Decision::Accept),
+        "R" => Rule::Unconditional(Decision::Reject),
+        workflow_name if !workflow_name.contains(':') => {
+            Rule::Unconditional(Decision::SendTo(workflow_name.to_string()))
+        }
+        _ => {
+            let (condition, decision) = split_once(input, ":")?;
+            let Some(i) = condition.find(['<', '>']) else {
+                return Err(ParseError::new(condition, "expected '<' or '>'"));
+            };
+            let (category, operator, value) =
+                (&condition[..i], &condition[i..i + 1], &condition[i + 1..]);
+            let category = parse_category(category)?;
+            let value = number(value)?;
+            let decision = match decision {
+                "A" => Decision::Accept,
+                "R" => Decision::Reject,
+                _ => Decision::SendTo(decision.to_string()),
+            };
+            match operator {
+                "<" => Rule::LessThan(category, value, decision),
+                _ => Rule::GreaterThan(category, value, decision),
+            }
+        }
+    })
+}
+
+fn parse_part(input: &str) -> Result<Part, ParseError> {
+    let ratings = strip_suffix(strip_prefix(input, "{")?, "}")?;
+    let mut part = Part {
+        x: 0,
+        m: 0,
+        a: 0,
+        s: 0,
+    };
+    for rating in ratings.split(',') {
+        let (category, value) = split_once(rating, "=")?;
+        let rating = number(value)?;
+        if !(1..=4000).contains(&rating) {
+            return Err(ParseError::new(value, "expected a rating from 1 to 4000"));
+        }
+        part[parse_category(category)?] = rating;
+    }
+    if let Some(category) = Category::ALL.into_iter().find(|&c| part[c] == 0) {
+        return Err(ParseError::new(
+            ratings,
+            format!("expected a rating for {}", category.name()),
+        ));
+    }
+    Ok(part)
+}
+
+/// The line of `input` defining the workflow `name`, or `input` itself if
+/// there is none.
+fn workflow_line<'a>(input: &'a str, name: &str) -> &'a str {
+    input
+        .lines()
+        .find(|line| line.split('{').next() == Some(name))
+        .unwrap_or(input)
+}
+
+/// Rejects workflows that would send some part nowhere or round in circles,
+/// and logs anything else [`lint`] finds.
+fn check(input: &str, workflows: Vec<Workflow>) -> Result<Workflows, ParseError> {
+    for lint in lint(&workflows) {
+        if !lint.is_error() {
+            log::debug!("{lint}");
+            continue;
+        }
+        let fragment = match &lint {
+            Lint::NoStart => &input[input.len()..],
+            Lint::Missing { workflow, target } => {
+                let line = workflow_line(input, workflow);
+                // Point at the rule's target rather than the whole line.
+                line.split(['{', ',', '}'])
+                    .filter_map(|rule| rule.rsplit(':').next())
+                    .find(|decision| decision == target)
+                    .unwrap_or(line)
+            }
+            Lint::Cycle(cycle) => workflow_line(input, &cycle[0]),
+            Lint::Incomplete(workflow) => workflow_line(input, workflow),
+            _ => unreachable!("{lint:?} is not an error"),
+        };
+        return Err(ParseError::new(fragment, lint.to_string()));
+    }
+    Ok(Workflows::checked(workflows))
+}
+
+fn parse(input: &str) -> Result<(Workflows, Vec<Part>), ParseError> {
+    let (workflows_input, parts) = split_once(input, "\n\n")?;
+    let workflows = workflows_input
+        .lines()
+        .map(parse_workflow)
+        .collect::<Result<_, _>>()?;
+    let workflows = check(workflows_input, workflows)?;
+    let parts = parts.lines().map(parse_part).collect::<Result<_, _>>()?;
+    Ok((workflows, parts))
+}
+
+fn organize(part: &Part, workflows: &Workflows) -> Decision {
+    let mut workflow = workflows.get("in");
+    loop {
+        let rule = workflow.rules.iter().find(|rule| rule.matches(part));
+        match rule.map(Rule::decision) {
+            Some(Decision::SendTo(name)) => workflow = workflows.get(name),
+            Some(decision) => return decision.clone(),
+            None => unreachable!("{} has a rule for every part", workflow.name),
+        }
+    }
+}
+
+/// Sorts every possible part by following the workflows from `in` with
+/// whole regions of parts at a time, splitting them wherever a rule does.
+/// The regions returned don't overlap, and between them cover every part.
+pub fn classify(workflows: &Workflows) -> Vec<Classified> {
+    let mut classified = Vec::new();
+    let mut stack = vec![(Region::full(), "in", Vec::new())];
+    while let Some((region, workflow_name, path)) = stack.pop() {
+        let workflow = workflows.get(workflow_name);
+        let mut rest = region;
+        for (i, rule) in workflow.rules.iter().enumerate() {
+            let (matched, unmatched) = rule.split(&rest);
+            rest = unmatched;
+            if !matched.is_empty() {
+                let mut path = path.clone();
+                path.push(Step {
+                    workflow: workflow_name.to_string(),
+                    rule: i,
+                });
+                match rule.decision() {
+                    Decision::SendTo(next) => stack.push((matched, next.as_str(), path)),
+                    decision => classified.push(Classified {
+                        region: matched,
+                        decision: decision.clone(),
+                        path,
+                    }),
+                }
+            }
+            if rest.is_empty() {
+                break;
+            }
+        }
+    }
+    classified
+}
+
+/// A problem with a set of workflows, as found by [`lint`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// There is no `in` workflow to start from.
+    NoStart,
+    /// `workflow` sends parts to `target`, which does not exist.
+    Missing { workflow: String, target: String },
+    /// The workflows send parts from each to the next and back to the first.
+    Cycle(Vec<String>),
+    /// Some parts match none of the rules of the workflow.
+    Incomplete(String),
+    /// No part can ever reach the workflow from `in`.
+    Unreachable(String),
+    /// The `rule`th rule of `workflow`, counting from 0, never matches any
+    /// part that the rules before it let through.
+    DeadRule { workflow: String, rule: usize },
+    /// Every rule of the workflow makes the same decision, so its conditions
+    /// make no difference.
+    Uniform {
+        workflow: String,
+        decision: Decision,
+    },
+}
+
+impl Lint {
+    /// Whether sorting parts with the workflows could fail, by sending a part
+    /// nowhere or round in circles. The other lints are merely redundancy.
+    pub fn is_error(&self) -> bool {
+        matches!(
+            self,
+            Lint::NoStart | Lint::Missing { .. } | Lint::Cycle(_) | Lint::Incomplete(_)
+        )
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Lint::NoStart => write!(f, "there is no workflow named in"),
+            Lint::Missing { workflow, target } => {
+                write!(
+                    f,
+                    "{workflow} sends parts to {target}, which does not exist"
+                )
+            }
+            Lint::Cycle(cycle) => {
+                write!(
+                    f,
+                    "workflows {} -> {} form a cycle",
+                    cycle.join(" -> "),
+                    cycle[0]
+                )
+            }
+            Lint::Incomplete(workflow) => {
+                write!(f, "some parts match none of the rules of {workflow}")
+            }
+            Lint::Unreachable(workflow) => write!(f, "{workflow} is unreachable from in"),
+            Lint::DeadRule { workflow, rule } => {
+                write!(f, "rule {} of {workflow} can never match", rule + 1)
+            }
+            Lint::Uniform { workflow, decision } => {
+                write!(f, "every rule of {workflow} leads to {decision}")
+            }
+        }
+    }
+}
+
+/// Checks workflows for anything that would stop [`classify`] or
+/// part one's sorting from finishing, and for rules and workflows that make
+/// no difference.
+pub fn lint(workflows: &[Workflow]) -> Vec<Lint> {
+    let name_to_workflow = workflows
+        .iter()
+        .map(|workflow| (workflow.name.as_str(), workflow))
+        .collect::<HashMap<_, _>>();
+    let mut reachable = HashSet::new();
+    let mut stack: Vec<&str> = vec!["in"];
+    while let Some(name) = stack.pop() {
+        if let Some(workflow) = name_to_workflow.get(name) {
+            if reachable.insert(name) {
+                stack.extend(workflow.targets());
+            }
+        }
+    }
+
+    let mut lints = Vec::new();
+    if !name_to_workflow.contains_key("in") {
+        lints.push(Lint::NoStart);
+    }
+    for workflow in workflows {
+        let name = &workflow.name;
+        for target in workflow.targets() {
+            if !name_to_workflow.contains_key(target) {
+                lints.push(Lint::Missing {
+                    workflow: name.clone(),
+                    target: target.to_string(),
+                });
+            }
+        }
+        if !reachable.contains(name.as_str()) {
+            lints.push(Lint::Unreachable(name.clone()));
+        }
+
+        let mut rest = Region::full();
+        let mut dead = Vec::new();
+        for (i, rule) in workflow.rules.iter().enumerate() {
+            let (matched, unmatched) = rule.split(&rest);
+            if matched.is_empty() {
+                dead.push(Lint::DeadRule {
+                    workflow: name.clone(),
+                    rule: i,
+                });
+            }
+            rest = unmatched;
+        }
+        if !rest.is_empty() {
+            lints.push(Lint::Incomplete(name.clone()));
+        }
+        lints.extend(dead);
+
+        if let [first, others @ ..] = &workflow.rules[..] {
+            if !others.is_empty()
+                && others
+                    .iter()
+                    .all(|rule| rule.decision() == first.decision())
+            {
+                lints.push(Lint::Uniform {
+                    workflow: name.clone(),
+                    decision: first.decision().clone(),
+                });
+            }
+        }
+    }
+
+    // Depth-first search, finding a cycle whenever it comes back to a
+    // workflow still on its path.
+    let mut done = HashSet::new();
+    for workflow in workflows {
+        let mut path: Vec<&str> = Vec::new();
+        let mut stack = vec![(workflow.name.as_str(), 0)];
+        while let Some((name, i)) = stack.pop() {
+            if i == 0 {
+                if done.contains(name) {
+                    continue;
+                }
+                if let Some(start) = path.iter().position(|&p| p == name) {
+                    lints.push(Lint::Cycle(
+                        path[start..].iter().map(|s| s.to_string()).collect(),
+                    ));
+                    continue;
+                }
+                path.push(name);
+            }
+            let next = name_to_workflow
+                .get(name)
+                .and_then(|workflow| workflow.targets().get(i).copied());
+            match next {
+                Some(next) => {
+                    stack.push((name, i + 1));
+                    stack.push((next, 0));
+                }
+                None => {
+                    path.pop();
+                    done.insert(name);
+                }
+            }
+        }
+    }
+    lints
+}
+
+/// The workflows followed from `in` all at once: which way a part goes at
+/// each split depends on one of its ratings, until it is accepted or
+/// rejected.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Tree {
+    Leaf(Decision),
+    /// Parts rated below `value` in `category` go `below`, the rest `above`.
+    Split {
+        category: Category,
+        value: u64,
+        below: Box<Tree>,
+        above: Box<Tree>,
+    },
+}
+
+impl Tree {
+    /// A split, or just one of the branches if they are the same.
+    fn split(category: Category, value: u64, below: Tree, above: Tree) -> Tree {
+        if below == above {
+            below
+        } else {
+            Tree::Split {
+                category,
+                value,
+                below: Box::new(below),
+                above: Box::new(above),
+            }
+        }
+    }
+
+    pub fn decide(&self, part: &Part) -> &Decision {
+        match self {
+            Tree::Leaf(decision) => decision,
+            Tree::Split {
+                category,
+                value,
+                below,
+                above,
+            } => {
+                if part[*category] < *value {
+                    below.decide(part)
+                } else {
+                    above.decide(part)
+                }
+            }
+        }
+    }
+
+    /// Writes the tree back out as workflows, starting from `in`. A chain of
+    /// splits each with a decision on one side becomes the rules of a single
+    /// workflow; where both sides split again, one of them gets a workflow of
+    /// its own.
+    pub fn to_workflows<'a>(&'a self) -> Vec<Workflow> {
+        let mut workflows = Vec::new();
+        let mut pending = vec![("in".to_string(), self)];
+        let mut names = (0..).map(workflow_name).filter(|name| name != "in");
+        while let Some((name, mut tree)) = pending.pop() {
+            let mut rules = Vec::new();
+            // Where a rule sends the parts taking the branch `tree`.
+            let mut target = |tree: &'a Tree, pending: &mut Vec<(String, &'a Tree)>| match tree {
+                Tree::Leaf(decision) => decision.clone(),
+                Tree::Split { .. } => {
+                    let name = names.next().unwrap();
+                    pending.push((name.clone(), tree));
+                    Decision::SendTo(name)
+                }
+            };
+            loop {
+                match tree {
+                    Tree::Leaf(decision) => {
+                        rules.push(Rule::Unconditional(decision.clone()));
+                        break;
+                    }
+                    Tree::Split {
+                        category,
+                        value,
+                        below,
+                        above,
+                    } => {
+                        if matches!(**below, Tree::Leaf(_)) || matches!(**above, Tree::Split { .. })
+                        {
+                            let decision = target(below, &mut pending);
+                            rules.push(Rule::LessThan(*category, *value, decision));
+                            tree = above;
+                        } else {
+                            let decision = target(above, &mut pending);
+                            rules.push(Rule::GreaterThan(*category, value - 1, decision));
+                            tree = below;
+                        }
+                    }
+                }
+            }
+            workflows.push(Workflow { name, rules });
+        }
+        workflows
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter, depth: usize) -> fmt::Result {
+        match self {
+            Tree::Leaf(decision) => writeln!(f, "{decision}"),
+            Tree::Split {
+                category,
+                value,
+                below,
+                above,
+            } => {
+                writeln!(f, "{}<{value}", category.name())?;
+                write!(f, "{:indent$}yes: ", "", indent = 2 * (depth + 1))?;
+                below.fmt_indented(f, depth + 1)?;
+                write!(f, "{:indent$}no: ", "", indent = 2 * (depth + 1))?;
+                above.fmt_indented(f, depth + 1)
+            }
+        }
+    }
+}
+
+/// Each split on a line of its own, with the branches indented below it:
+///
+/// ```text
+/// x<5
+///   yes: A
+///   no: m<11
+///     yes: A
+///     no: R
+/// ```
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+/// The `n`th of the names `a`, `b`, ..., `z`, `aa`, `ab`, ...
+fn workflow_name(n: usize) -> String {
+    let mut name = Vec::new();
+    let mut n = n + 1;
+    while n > 0 {
+        n -= 1;
+        name.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+/// Inlines the workflows from `in` into a single tree, leaving out the
+/// branches no part can take, and collapsing every split whose branches
+/// agree.
+pub fn decision_tree(workflows: &Workflows) -> Tree {
+    build(workflows, &workflows.get("in").rules, Region::full())
+}
+
+/// The tree for the parts in `region` that reach `rules`, the rules of a
+/// workflow that the parts have not matched any before.
+fn build(workflows: &Workflows, rules: &[Rule], region: Region) -> Tree {
+    // A workflow has a rule for every part that reaches it, so the parts
+    // cannot run out of rules.
+    let [current, others @ ..] = rules else {
+        unreachable!("parts in {region} match no rule");
+    };
+    let (matched, rest) = current.split(&region);
+    let follow = |region| match current.decision() {
+        Decision::SendTo(next) => build(workflows, &workflows.get(next).rules, region),
+        decision => Tree::Leaf(decision.clone()),
+    };
+    if matched.is_empty() {
+        return build(workflows, others, rest);
+    }
+    if rest.is_empty() {
+        return follow(matched);
+    }
+    match *current {
+        Rule::LessThan(category, value, _) => Tree::split(
+            category,
+            value,
+            follow(matched),
+            build(workflows, others, rest),
+        ),
+        // Some parts are rated above `value`, so it is below 4000 and adding
+        // one cannot overflow.
+        Rule::GreaterThan(category, value, _) => Tree::split(
+            category,
+            value + 1,
+            build(workflows, others, rest),
+            follow(matched),
+        ),
+        Rule::Unconditional(_) => unreachable!("an unconditional rule matches everything"),
+    }
+}
+
+/// Rewrites workflows as the fewest that sort parts the same way, by way of
+/// their [decision tree](decision_tree).
+pub fn simplify(workflows: &Workflows) -> Workflows {
+    // Every workflow written out from a tree ends in a decision for all the
+    // parts left, and only sends parts further down the tree.
+    let simplified = decision_tree(workflows).to_workflows();
+    debug_assert!(lint(&simplified).iter().all(|lint| !lint.is_error()));
+    Workflows::checked(simplified)
+}
+
+/// Whether two sets of workflows accept exactly the same parts.
+pub fn equivalent(a: &Workflows, b: &Workflows) -> bool {
+    let (a, b) = (classify(a), classify(b));
+    a.iter().all(|x| {
+        b.iter()
+            .all(|y| x.decision == y.decision || x.region.intersection(&y.region).is_empty())
+    })
+}
+
+fn part_one(input: &(Workflows, Vec<Part>)) -> u64 {
+    let (workflows, parts) = input;
+    parts
+        .iter()
+        .filter(|part| organize(part, workflows) == Decision::Accept)
+        .map(|part| part.x + part.m + part.a + part.s)
+        .sum()
+}
+
+fn part_two(workflows: &Workflows) -> u64 {
+    classify(workflows)
+        .iter()
+        .filter(|classified| classified.decision == Decision::Accept)
+        .map(|classified| classified.region.volume())
+        .sum()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Workflows, Vec<Part>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(system: &Self::Input) -> Answer {
+        part_one(system).into()
+    }
+
+    fn part_two(system: &Self::Input) -> Answer {
+        part_two(&system.0).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = include_str!("../../examples/2023-19/01.txt");
+
+    #[test]
+    fn test_classify() {
+        let (workflows, parts) = parse(EXAMPLE).unwrap();
+        let classified = classify(&workflows);
+        let volume = |decision: Decision| -> u64 {
+            classified
+                .iter()
+                .filter(|c| c.decision == decision)
+                .map(|c| c.region.volume())
+                .sum()
+        };
+        assert_eq!(volume(Decision::Accept), 167409079868000);
+        assert_eq!(
+            volume(Decision::Accept) + volume(Decision::Reject),
+            4000_u64.pow(4)
+        );
+
+        for part in &parts {
+            let regions: Vec<_> = classified
+                .iter()
+                .filter(|c| c.region.contains(part))
+                .collect();
+            assert_eq!(regions.len(), 1, "{part:?}");
+            assert_eq!(regions[0].decision, organize(part, &workflows));
+            assert_eq!(regions[0].path[0].workflow, "in");
+        }
+    }
+
+    #[test]
+    fn test_lint() {
+        let workflows = indoc! {"
+            in{x>10:a,b}
+            a{m<5:A,m<3:R,R}
+            b{x<5:e,zz}
+            e{f}
+            f{x>2:e,R}
+            d{a>5:A,A}
+            g{s>5:A}
+        "}
+        .lines()
+        .map(parse_workflow)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+        let lints = lint(&workflows);
+        assert_eq!(
+            lints.iter().map(Lint::to_string).collect::<Vec<_>>(),
+            [
+                "rule 2 of a can never match",
+                "b sends parts to zz, which does not exist",
+                "d is unreachable from in",
+                "every rule of d leads to A",
+                "g is unreachable from in",
+                "some parts match none of the rules of g",
+                "workflows e -> f -> e form a cycle",
+            ]
+        );
+        assert!(lint(parse(EXAMPLE).unwrap().0.as_slice())
+            .iter()
+            .all(|lint| !lint.is_error()));
+        assert_eq!(
+            Workflows::new(workflows).unwrap_err(),
+            Lint::Missing {
+                workflow: "b".to_string(),
+                target: "zz".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let err = parse("in{x>1:A,zz}\n\n{x=1,m=1,a=1,s=1}\n").unwrap_err();
+        assert_eq!(err.text, "zz");
+        assert_eq!(err.message, "in sends parts to zz, which does not exist");
+        let err = parse("in{x>1:A,in}\n\n{x=1,m=1,a=1,s=1}\n").unwrap_err();
+        assert_eq!(err.message, "workflows in -> in form a cycle");
+        let err = parse("in{x>1:A}\n\n{x=1,m=1,a=1,s=1}\n").unwrap_err();
+        assert_eq!(err.message, "some parts match none of the rules of in");
+        let err = parse("in{A}\n\n{x=1,m=4001,a=1,s=1}\n").unwrap_err();
+        assert_eq!(err.text, "4001");
+        let err = parse("in{A}\n\n{x=1,m=1,a=1}\n").unwrap_err();
+        assert_eq!(err.message, "expected a rating for s");
+    }
+
+    #[test]
+    fn test_simplify() {
+        let (workflows, parts) = parse(EXAMPLE).unwrap();
+        let simplified = simplify(&workflows);
+        assert!(equivalent(&workflows, &simplified));
+        let rules = |workflows: &Workflows| {
+            workflows
+                .as_slice()
+                .iter()
+                .map(|w| w.rules.len())
+                .sum::<usize>()
+        };
+        assert!(rules(&simplified) < rules(&workflows));
+
+        // The simplified workflows parse back to the same thing.
+        let text: String = simplified
+            .as_slice()
+            .iter()
+            .map(|w| format!("{w}\n"))
+            .collect();
+        let reparsed = text
+            .lines()
+            .map(parse_workflow)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(reparsed, simplified.as_slice());
+
+        let tree = decision_tree(&workflows);
+        for part in &parts {
+            assert_eq!(*tree.decide(part), organize(part, &workflows));
+        }
+
+        let mut changed = workflows.as_slice().to_vec();
+        changed[0].rules[0] = parse_rule("s<1352:R").unwrap();
+        assert!(!equivalent(&workflows, &Workflows::new(changed).unwrap()));
+    }
+
+    #[test]
+    fn test_decision_tree() {
+        let workflows = ["in{x<5:A,lnx}", "lnx{m>10:gd,A}", "gd{a>3333:R,R}"]
+            .map(|workflow| parse_workflow(workflow).unwrap());
+        let workflows = Workflows::new(workflows.to_vec()).unwrap();
+        let tree = decision_tree(&workflows);
+        assert_eq!(
+            tree.to_string(),
+            indoc! {"
+                x<5
+                  yes: A
+                  no: m<11
+                    yes: A
+                    no: R
+            "}
+        );
+        assert_eq!(
+            tree.to_workflows()
+                .iter()
+                .map(Workflow::to_string)
+                .collect::<Vec<_>>(),
+            ["in{x<5:A,m<11:A,R}"]
+        );
+    }
+
+    #[test]
+    fn test_region() {
+        let (below, rest) = Region::full().split_at(Category::M, 1001);
+        assert_eq!(below.volume(), 1000 * 4000_u64.pow(3));
+        assert_eq!(
+            rest.to_string(),
+            "{x=1..=4000,m=1001..=4000,a=1..=4000,s=1..=4000}"
+        );
+        assert!(below.split_at(Category::M, 1).0.is_empty());
+        let never = Rule::GreaterThan(Category::X, u64::MAX, Decision::Accept);
+        assert_eq!(never.split(&below), (Region::empty(), below.clone()));
+    }
+}
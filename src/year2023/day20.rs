@@ -1,7 +1,14 @@
 use std::collections::{HashMap, VecDeque};
 
+use crate::{
+    grid::Grid,
+    image::{self, Raster},
+    parse::{split_once, ParseError},
+    record, Answer, Solution,
+};
+
 #[derive(Clone, Debug, PartialEq)]
-enum Module {
+pub enum Module {
     FlipFlop {
         on: bool,
         destinations: Vec<String>,
@@ -17,7 +24,7 @@ enum Module {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-enum Pulse {
+pub enum Pulse {
     High,
     Low,
 }
@@ -33,19 +40,19 @@ impl Module {
     }
 }
 
-fn parse(input: &str) -> HashMap<String, Module> {
+fn parse(input: &str) -> Result<HashMap<String, Module>, ParseError> {
     let mut configuration = HashMap::new();
 
     for line in input.lines() {
-        let (left, right) = line.split_once(" -> ").unwrap();
+        let (left, right) = split_once(line, " -> ")?;
         let destinations = right.split(", ").map(|s| s.to_string()).collect();
         if left == "broadcaster" {
             configuration.insert(left.to_string(), Module::Broadcast { destinations });
             continue;
         }
-        if left.starts_with('%') {
+        if let Some(name) = left.strip_prefix('%') {
             configuration.insert(
-                left.strip_prefix('%').unwrap().to_string(),
+                name.to_string(),
                 Module::FlipFlop {
                     on: false,
                     destinations: destinations.clone(),
@@ -53,9 +60,9 @@ fn parse(input: &str) -> HashMap<String, Module> {
             );
             continue;
         }
-        if left.starts_with('&') {
+        if let Some(name) = left.strip_prefix('&') {
             configuration.insert(
-                left.strip_prefix('&').unwrap().to_string(),
+                name.to_string(),
                 Module::Conjuction {
                     memory: HashMap::new(),
                     destinations: destinations.clone(),
@@ -63,7 +70,10 @@ fn parse(input: &str) -> HashMap<String, Module> {
             );
             continue;
         }
-        unreachable!();
+        return Err(ParseError::new(left, format!("unknown module {left:?}")));
+    }
+    if !configuration.contains_key("broadcaster") {
+        return Err(ParseError::end_of(input, "expected a broadcaster"));
     }
 
     for (name, module) in configuration.clone().iter_mut() {
@@ -76,7 +86,31 @@ fn parse(input: &str) -> HashMap<String, Module> {
             }
         }
     }
-    configuration
+    Ok(configuration)
+}
+
+/// Pictures the state of every module, in a square laid out by name:
+/// flip-flops lit when on, conjunctions hotter the more of their inputs were
+/// last high, and the rest grey.
+pub fn picture(configuration: &HashMap<String, Module>) -> Raster {
+    let mut names: Vec<&String> = configuration.keys().collect();
+    names.sort();
+    let width = ((names.len() as f64).sqrt().ceil() as usize).max(1);
+    let height = names.len().div_ceil(width);
+    let mut cells: Vec<image::Rgb> = names
+        .iter()
+        .map(|&name| match &configuration[name] {
+            Module::FlipFlop { on: true, .. } => [120, 230, 120],
+            Module::FlipFlop { on: false, .. } => [30, 60, 30],
+            Module::Conjuction { memory, .. } => {
+                let high = memory.values().filter(|&&p| p == Pulse::High).count();
+                image::heat(high as f64 / memory.len().max(1) as f64)
+            }
+            _ => [110, 110, 110],
+        })
+        .collect();
+    cells.resize(width * height, image::BLACK);
+    Raster::from_grid(&Grid::from_vec(width, height, cells), 16, |&c| c)
 }
 
 fn part_one(configuration: &mut HashMap<String, Module>) -> u64 {
@@ -125,6 +159,7 @@ fn part_one(configuration: &mut HashMap<String, Module>) -> u64 {
                 }
             }
         }
+        record::frame(|| picture(configuration));
     }
     low_count * high_count
 }
@@ -136,7 +171,7 @@ fn part_two(configuration: &mut HashMap<String, Module>) -> u64 {
         while let Some(item) = queue.pop_front() {
             let (input, name, pulse) = item;
             if name == "xm" && pulse == Pulse::High {
-                dbg!(i, &input);
+                log::debug!("Press {i}: {input} sent xm a high pulse");
             }
 
             let module = configuration.get_mut(&name).unwrap();
@@ -170,45 +205,38 @@ fn part_two(configuration: &mut HashMap<String, Module>) -> u64 {
                 }
             }
         }
+        record::frame(|| picture(configuration));
     }
     unreachable!();
 }
 
-fn main() {
-    let configuration = parse(include_str!("../input/2023-20.txt"));
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = HashMap<String, Module>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(configuration: &Self::Input) -> Answer {
+        part_one(&mut configuration.clone()).into()
+    }
 
-    println!("Part one: {}", part_one(&mut configuration.clone()));
-    println!("Part two: {}", part_two(&mut configuration.clone()));
+    fn part_two(configuration: &Self::Input) -> Answer {
+        part_two(&mut configuration.clone()).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    const EXAMPLE_ONE: &str = indoc! {"
-        broadcaster -> a, b, c
-        %a -> b
-        %b -> c
-        %c -> inv
-        &inv -> a
-    "};
-
-    const EXAMPLE_TWO: &str = indoc! {"
-        broadcaster -> a
-        %a -> inv, con
-        &inv -> b
-        %b -> con
-        &con -> output
-"};
-
-    #[test]
-    fn test_part_one_example_one() {
-        assert_eq!(part_one(&mut parse(EXAMPLE_ONE)), 32000000);
-    }
 
     #[test]
-    fn test_part_one_example_two() {
-        assert_eq!(part_one(&mut parse(EXAMPLE_TWO)), 11687500);
+    fn test_malformed() {
+        let err = parse("%a -> b\n&b -> a\n").unwrap_err();
+        assert_eq!(err.message, "expected a broadcaster");
+        let err = parse("broadcaster -> a\n$a -> b\n").unwrap_err();
+        assert_eq!(err.text, "$a");
     }
 }
@@ -0,0 +1,100 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    grid::Grid,
+    image::{self, Raster},
+    parse::{find_one, ParseError},
+    point::Point,
+    record, Answer, Params, Solution,
+};
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    let map = Grid::parse(input, |c| ".#S".contains(c).then_some(c))?;
+    find_one(input, 'S')?;
+    Ok(map)
+}
+
+/// Pictures the search: the plots reached so far, lighter on an even number
+/// of steps than an odd one, and the frontier still to be explored.
+fn draw(
+    map: &Grid<char>,
+    start: Point,
+    visited: &HashSet<Point>,
+    frontier: impl IntoIterator<Item = Point>,
+) -> Raster {
+    let mut cells = map.map(|&c| match c {
+        '#' => [70, 70, 80],
+        _ => image::BLACK,
+    });
+    for &p in visited {
+        cells[p] = match p.manhattan(start) % 2 {
+            0 => [110, 200, 110],
+            _ => [40, 90, 40],
+        };
+    }
+    for p in frontier {
+        cells[p] = [250, 220, 90];
+    }
+    Raster::from_grid(&cells, 4, |&c| c)
+}
+
+fn part_one(map: &Grid<char>, max_steps: u64) -> u64 {
+    let start = map.find(&'S').expect("parse checked for a start");
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut layer = 0;
+    while let Some((position, steps)) = queue.pop_front() {
+        if steps > max_steps {
+            break;
+        }
+        if steps > layer {
+            layer = steps;
+            record::frame(|| {
+                let frontier = queue.iter().map(|&(p, _)| p).chain([position]);
+                draw(map, start, &visited, frontier)
+            });
+        }
+        if visited.contains(&position) {
+            continue;
+        }
+        visited.insert(position);
+        for neighbour in map.neighbours4(position) {
+            if map[neighbour] != '#' {
+                queue.push_back((neighbour, steps + 1));
+            }
+        }
+    }
+
+    visited
+        .iter()
+        .filter(|&&p| p.manhattan(start) % 2 == 0)
+        .count() as u64
+}
+
+fn part_two(_map: &Grid<char>) -> u64 {
+    0
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(map: &Self::Input) -> Answer {
+        Self::part_one_with(map, &Params::default())
+    }
+
+    /// The number of steps defaults to the real input's 64.
+    fn part_one_with(map: &Self::Input, params: &Params) -> Answer {
+        let steps = params.get("steps").unwrap_or(64);
+        part_one(map, steps as u64).into()
+    }
+
+    fn part_two(map: &Self::Input) -> Answer {
+        part_two(map).into()
+    }
+}
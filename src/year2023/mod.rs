@@ -0,0 +1,49 @@
+//! Solutions for [Advent of Code 2023](https://adventofcode.com/2023).
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+
+use crate::Day;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(2023, 1),
+    Day::new::<day02::Day02>(2023, 2),
+    Day::new::<day03::Day03>(2023, 3),
+    Day::new::<day04::Day04>(2023, 4),
+    Day::new::<day05::Day05>(2023, 5),
+    Day::new::<day06::Day06>(2023, 6),
+    Day::new::<day07::Day07>(2023, 7),
+    Day::new::<day08::Day08>(2023, 8),
+    Day::new::<day09::Day09>(2023, 9),
+    Day::new::<day10::Day10>(2023, 10),
+    Day::new::<day11::Day11>(2023, 11),
+    Day::new::<day12::Day12>(2023, 12),
+    Day::new::<day13::Day13>(2023, 13),
+    Day::new::<day14::Day14>(2023, 14),
+    Day::new::<day15::Day15>(2023, 15),
+    Day::new::<day16::Day16>(2023, 16),
+    Day::new::<day17::Day17>(2023, 17),
+    Day::new::<day18::Day18>(2023, 18),
+    Day::new::<day19::Day19>(2023, 19),
+    Day::new::<day20::Day20>(2023, 20),
+    Day::new::<day21::Day21>(2023, 21),
+];